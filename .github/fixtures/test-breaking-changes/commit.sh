@@ -0,0 +1,10 @@
+#!/usr/bin/env bash
+set -e
+
+GIT_COMMITTER_DATE="2022-04-06 01:25:08" git commit --allow-empty -m "feat: add the parser"
+GIT_COMMITTER_DATE="2022-04-06 01:25:09" git commit --allow-empty -m "feat(api)!: remove the v1 endpoints"
+GIT_COMMITTER_DATE="2022-04-06 01:25:10" git commit --allow-empty -m "fix: handle the empty messages"
+GIT_COMMITTER_DATE="2022-04-06 01:25:11" git commit --allow-empty -m "refactor: rename the config options
+
+BREAKING CHANGE: \`foo\` option is renamed to \`bar\`"
+git tag v0.1.0
@@ -0,0 +1,15 @@
+#!/usr/bin/env bash
+set -e
+
+echo "parser" >parser.txt && git add parser.txt
+GIT_COMMITTER_DATE="2022-04-06 01:25:08" git commit -m "feat: add the parser"
+echo "cache" >cache.txt && git add cache.txt
+GIT_COMMITTER_DATE="2022-04-06 01:25:09" git commit -m "feat: add the cache"
+git tag v0.1.0
+echo "api" >api.txt && git add api.txt
+GIT_COMMITTER_DATE="2022-04-06 01:25:10" git commit -m "feat: add an experimental API"
+echo "fixed" >>parser.txt && git add parser.txt
+GIT_COMMITTER_DATE="2022-04-06 01:25:11" git commit -m "fix: handle the empty messages"
+GIT_COMMITTER_DATE="2022-04-06 01:25:12" git revert --no-edit HEAD~1
+GIT_COMMITTER_DATE="2022-04-06 01:25:13" git revert --no-edit v0.1.0
+git tag v0.2.0
@@ -0,0 +1,17 @@
+#!/usr/bin/env bash
+set -e
+
+GIT_COMMITTER_DATE="2022-04-06 01:25:08" git commit --allow-empty -m "Initial commit"
+GIT_COMMITTER_DATE="2022-04-06 01:25:09" git commit --allow-empty -m "Support the legacy messages
+
+Reviewed-by: John Doe <john@doe.com>
+Changelog: added
+Changelog-Entry: Changelogs can now be generated from legacy commit
+  messages"
+GIT_COMMITTER_DATE="2022-04-06 01:25:10" git commit --allow-empty -m "Refactor the parser
+
+Changelog: skip"
+GIT_COMMITTER_DATE="2022-04-06 01:25:11" git commit --allow-empty -m "Fix the empty footers
+
+Changelog: fixed"
+git tag v0.1.0
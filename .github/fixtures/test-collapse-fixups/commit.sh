@@ -0,0 +1,14 @@
+#!/usr/bin/env bash
+set -e
+
+GIT_COMMITTER_DATE="2022-04-06 01:25:08" git commit --allow-empty -m "feat: add the parser"
+GIT_COMMITTER_DATE="2022-04-06 01:25:09" git commit --allow-empty -m "fix: handle the empty messages"
+GIT_COMMITTER_DATE="2022-04-06 01:25:10" git commit --allow-empty -m "fixup! feat: add the parser"
+GIT_COMMITTER_DATE="2022-04-06 01:25:11" git commit --allow-empty -m "squash! feat: add the parser"
+git tag v0.1.0
+GIT_COMMITTER_DATE="2022-04-06 01:25:12" git commit --allow-empty -m "fixup! fix: handle the empty messages"
+GIT_COMMITTER_DATE="2022-04-06 01:25:13" git commit --allow-empty -m "feat: support the legacy messages"
+GIT_COMMITTER_DATE="2022-04-06 01:25:14" git commit --allow-empty -m "amend! feat: support the legacy messages
+
+feat: support the legacy and conventional messages"
+git tag v0.2.0
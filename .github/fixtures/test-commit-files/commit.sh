@@ -0,0 +1,19 @@
+#!/usr/bin/env bash
+set -e
+
+mkdir -p src docs
+printf "fn main() {}\n" >src/main.rs
+printf "# Usage\n" >docs/usage.md
+git add src docs
+GIT_COMMITTER_DATE="2022-04-06 01:25:08" git commit -m "feat: initial commit"
+git tag v0.1.0
+
+printf "fn main() {\n    println!();\n}\n" >src/main.rs
+printf "pub fn run() {}\n" >src/lib.rs
+git add src
+GIT_COMMITTER_DATE="2022-04-06 01:25:09" git commit -m "feat: add the library"
+git mv docs/usage.md docs/guide.md
+printf "Changelog generator\n" >README.md
+git add README.md
+GIT_COMMITTER_DATE="2022-04-06 01:25:10" git commit -m "docs: rename the usage guide"
+git tag v0.2.0
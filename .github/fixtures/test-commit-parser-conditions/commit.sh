@@ -0,0 +1,9 @@
+#!/usr/bin/env bash
+set -e
+
+GIT_COMMITTER_DATE="2022-04-06 01:25:08" git commit --allow-empty -m "feat: initial commit"
+GIT_AUTHOR_NAME="John Doe" GIT_COMMITTER_DATE="2022-04-06 01:25:09" git commit --allow-empty -m "fix(api): handle errors"
+GIT_AUTHOR_NAME="renovate[bot]" GIT_COMMITTER_DATE="2022-04-06 01:25:10" git commit --allow-empty -m "fix(api): update dependencies"
+GIT_AUTHOR_NAME="John Doe" GIT_COMMITTER_DATE="2022-04-06 01:25:11" git commit --allow-empty -m "fix(ui): fix the layout"
+GIT_COMMITTER_DATE="2022-04-06 01:25:12" git commit --allow-empty -m "chore: update the changelog"
+git tag v0.1.0
@@ -0,0 +1,21 @@
+#!/usr/bin/env bash
+set -e
+
+echo "# Project" >README.md
+echo "fn main() {}" >main.rs
+git add .
+GIT_COMMITTER_DATE="2022-04-06 01:25:08" git commit -m "feat: initial commit"
+git tag v0.1.0
+
+mkdir -p docs
+echo "# Usage" >docs/usage.md
+git add .
+GIT_COMMITTER_DATE="2022-04-06 01:25:09" git commit -m "add usage guide"
+echo "lock" >Cargo.lock
+git add .
+GIT_COMMITTER_DATE="2022-04-06 01:25:10" git commit -m "chore: update dependencies"
+echo "fn main() { println!(); }" >main.rs
+echo "# Changes" >>README.md
+git add .
+GIT_COMMITTER_DATE="2022-04-06 01:25:11" git commit -m "fix: print a new line"
+git tag v0.2.0
@@ -0,0 +1,34 @@
+#!/usr/bin/env bash
+set -e
+
+echo "parser" >parser.txt && git add parser.txt
+GIT_COMMITTER_DATE="2022-04-06 01:25:08" git commit -m "feat: add the parser"
+git tag v1.0.0
+git checkout -b release/1.x
+git checkout -
+echo "cache" >cache.txt && git add cache.txt
+GIT_COMMITTER_DATE="2022-04-06 01:25:09" git commit -m "feat: add the cache"
+echo "fixed" >>parser.txt && git add parser.txt
+GIT_COMMITTER_DATE="2022-04-06 01:25:10" git commit -m "fix: handle the empty messages"
+empty_messages_fix=$(git rev-parse HEAD)
+echo "fixed" >>cache.txt && git add cache.txt
+GIT_COMMITTER_DATE="2022-04-06 01:25:11" git commit -m "fix: invalidate the cache"
+git checkout release/1.x
+GIT_COMMITTER_DATE="2022-04-06 01:25:12" git cherry-pick -x "$empty_messages_fix"
+git tag v1.0.1
+git checkout -
+GIT_COMMITTER_DATE="2022-04-06 01:25:13" git merge --no-ff -m "Merge branch 'release/1.x'" release/1.x
+echo "api" >api.txt && git add api.txt
+GIT_COMMITTER_DATE="2022-04-06 01:25:14" git commit -m "feat: add the API"
+echo "unicode" >>parser.txt && git add parser.txt
+GIT_COMMITTER_DATE="2022-04-06 01:25:15" git commit -m "fix: handle the unicode messages"
+unicode_messages_fix=$(git rev-parse HEAD)
+git tag v1.1.0
+git checkout release/1.x
+GIT_COMMITTER_DATE="2022-04-06 01:25:16" git cherry-pick -x "$unicode_messages_fix"
+git tag v1.0.2
+git checkout -
+GIT_COMMITTER_DATE="2022-04-06 01:25:17" git merge --no-ff -m "Merge branch 'release/1.x'" release/1.x
+echo "cli" >cli.txt && git add cli.txt
+GIT_COMMITTER_DATE="2022-04-06 01:25:18" git commit -m "feat: add the CLI"
+git tag v1.2.0
@@ -0,0 +1,15 @@
+#!/usr/bin/env bash
+set -e
+
+GIT_COMMITTER_DATE="2022-04-06 01:25:08" git commit --allow-empty -m "feat: initial commit"
+git checkout -b parser
+GIT_COMMITTER_DATE="2022-04-06 01:25:09" git commit --allow-empty -m "feat: add the parser"
+GIT_COMMITTER_DATE="2022-04-06 01:25:10" git commit --allow-empty -m "fix: handle the errors in parser"
+git checkout -
+GIT_COMMITTER_DATE="2022-04-06 01:25:11" git merge --no-ff --no-edit \
+	-m "Merge pull request #1 from user/parser" -m "feat: support parsing the input" parser
+git checkout -b fix-layout
+GIT_COMMITTER_DATE="2022-04-06 01:25:12" git commit --allow-empty -m "fix: fix the layout"
+git checkout -
+GIT_COMMITTER_DATE="2022-04-06 01:25:13" git merge --no-ff --no-edit fix-layout
+git tag v0.1.0
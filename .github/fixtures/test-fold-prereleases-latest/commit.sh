@@ -0,0 +1,12 @@
+#!/usr/bin/env bash
+set -e
+
+GIT_COMMITTER_DATE="2022-04-06 01:25:08" git commit --allow-empty -m "feat: add the parser"
+git tag v1.0.0
+GIT_COMMITTER_DATE="2022-04-06 01:25:09" git commit --allow-empty -m "feat: add the new API"
+GIT_COMMITTER_DATE="2022-04-06 01:25:10" git commit --allow-empty -m "fix: handle the errors in parser"
+git tag v2.0.0-rc.1
+GIT_COMMITTER_DATE="2022-04-06 01:25:11" git commit --allow-empty -m "fix: fix the API layout"
+git tag v2.0.0-rc.2
+GIT_COMMITTER_DATE="2022-04-06 01:25:12" git commit --allow-empty -m "feat: support the new config format"
+git tag v2.0.0
@@ -0,0 +1,7 @@
+#!/usr/bin/env bash
+set -e
+
+GIT_AUTHOR_NAME="John Doe" GIT_AUTHOR_EMAIL="john@example.com" GIT_COMMITTER_DATE="2022-04-06 01:25:08" git commit --allow-empty -m "feat: add the parser"
+GIT_AUTHOR_NAME="jdoe" GIT_AUTHOR_EMAIL="jdoe@old-laptop.local" GIT_COMMITTER_DATE="2022-04-06 01:25:09" git commit --allow-empty -m "feat: add the cache"
+GIT_AUTHOR_NAME="jane" GIT_AUTHOR_EMAIL="jane@old-laptop.local" GIT_COMMITTER_DATE="2022-04-06 01:25:10" git commit --allow-empty -m "fix: handle the empty messages"
+git tag v0.1.0
@@ -0,0 +1,27 @@
+#!/usr/bin/env bash
+set -e
+
+mkdir -p core cli
+echo "core" >core/lib.rs
+echo "cli" >cli/main.rs
+git add .
+GIT_COMMITTER_DATE="2022-04-06 01:25:08" git commit -m "Initial commit"
+git tag core-v0.1.0
+git tag cli-v0.1.0
+
+echo "feature" >>core/lib.rs
+git add .
+GIT_COMMITTER_DATE="2022-04-06 01:25:09" git commit -m "feat(core): add feature 1"
+echo "fix" >>cli/main.rs
+git add .
+GIT_COMMITTER_DATE="2022-04-06 01:25:10" git commit -m "fix(cli): fix argument parsing"
+git tag cli-v0.2.0
+
+echo "fix" >>core/lib.rs
+git add .
+GIT_COMMITTER_DATE="2022-04-06 01:25:11" git commit -m "fix(core): fix feature 1"
+git tag core-v0.2.0
+
+echo "feature" >>cli/main.rs
+git add .
+GIT_COMMITTER_DATE="2022-04-06 01:25:12" git commit -m "feat(cli): add new flag"
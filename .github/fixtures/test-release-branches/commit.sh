@@ -0,0 +1,11 @@
+#!/usr/bin/env bash
+set -e
+
+GIT_COMMITTER_DATE="2022-04-06 01:25:08" git commit --allow-empty -m "feat: add the parser"
+GIT_COMMITTER_DATE="2022-04-06 01:25:09" git commit --allow-empty -m "fix: handle the errors in parser"
+git checkout -b release/2024.10
+GIT_COMMITTER_DATE="2022-04-06 01:25:10" git commit --allow-empty -m "fix: backport the parser fix"
+git checkout -
+GIT_COMMITTER_DATE="2022-04-06 01:25:11" git commit --allow-empty -m "feat: add the new API"
+git branch release/2024.11
+GIT_COMMITTER_DATE="2022-04-06 01:25:12" git commit --allow-empty -m "feat: support the plugins"
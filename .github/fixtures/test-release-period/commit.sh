@@ -0,0 +1,11 @@
+#!/usr/bin/env bash
+set -e
+
+GIT_COMMITTER_DATE="2022-08-30 10:00:00 +0000" git commit --allow-empty -m "feat: add the parser"
+git tag v0.1.0
+GIT_COMMITTER_DATE="2022-09-01 10:00:00 +0000" git commit --allow-empty -m "feat: add the new API"
+GIT_COMMITTER_DATE="2022-09-15 10:00:00 +0000" git commit --allow-empty -m "fix: handle the errors in parser"
+git tag v0.2.0
+GIT_COMMITTER_DATE="2022-09-30 10:00:00 +0000" git commit --allow-empty -m "fix: fix the API layout"
+GIT_COMMITTER_DATE="2022-10-03 10:00:00 +0000" git commit --allow-empty -m "feat: support the plugins"
+GIT_COMMITTER_DATE="2022-11-01 10:00:00 +0000" git commit --allow-empty -m "feat: add the config format"
@@ -0,0 +1,6 @@
+#!/usr/bin/env bash
+set -e
+
+GIT_AUTHOR_DATE="2022-08-30 10:00:00 +0000" GIT_COMMITTER_DATE="2022-08-30 10:00:00 +0000" git commit --allow-empty -m "feat: add the parser"
+GIT_AUTHOR_DATE="2022-09-02 10:00:00 +0000" GIT_COMMITTER_DATE="2022-08-31 10:00:00 +0000" git commit --allow-empty -m "feat: add the new API"
+GIT_AUTHOR_DATE="2022-09-15 10:00:00 +0000" GIT_COMMITTER_DATE="2022-09-15 10:00:00 +0000" git commit --allow-empty -m "fix: handle the errors in parser"
@@ -0,0 +1,14 @@
+#!/usr/bin/env bash
+set -e
+
+GIT_COMMITTER_DATE="2022-04-06 01:25:08" git commit --allow-empty -m "feat: add the parser"
+git tag v1.4.0
+GIT_COMMITTER_DATE="2022-04-06 01:25:09" git commit --allow-empty -m "feat: add the new API"
+git tag v2.0.0
+git checkout -b release/1.4 v1.4.0
+GIT_COMMITTER_DATE="2022-04-06 01:25:10" git commit --allow-empty -m "fix: handle the errors in parser"
+git tag v1.4.1
+git checkout -
+git merge --no-edit release/1.4
+GIT_COMMITTER_DATE="2022-04-06 01:25:11" git commit --allow-empty -m "fix: fix the API layout"
+git tag v2.0.1
@@ -1,4 +1,6 @@
 use crate::commit::Commit;
+#[cfg(feature = "remote")]
+use crate::config::Remote;
 use crate::config::{
 	Config,
 	GitConfig,
@@ -16,8 +18,18 @@ use crate::remote::gitea::GiteaClient;
 use crate::remote::github::GitHubClient;
 #[cfg(feature = "gitlab")]
 use crate::remote::gitlab::GitLabClient;
-use crate::template::Template;
-use std::collections::HashMap;
+#[cfg(feature = "remote")]
+use crate::remote::RemoteMetadata;
+use crate::template::{
+	Template,
+	DEPRECATED_VARIABLES,
+};
+use std::cell::RefCell;
+use std::collections::{
+	HashMap,
+	HashSet,
+};
+use std::fmt;
 use std::io::{
 	Read,
 	Write,
@@ -27,6 +39,46 @@ use std::time::{
 	UNIX_EPOCH,
 };
 
+/// Cache of the processed commits.
+///
+/// It can be shared between the changelogs that are generated with the same
+/// git configuration (e.g. for the projects of a monorepo) so that each
+/// commit is processed only once. The metadata of the remotes is also shared
+/// so that it is fetched only once.
+#[derive(Default)]
+pub struct CommitCache<'a> {
+	commits:         RefCell<HashMap<String, Option<Commit<'a>>>>,
+	#[cfg(feature = "remote")]
+	remote_metadata: RefCell<HashMap<String, RemoteMetadata>>,
+}
+
+impl fmt::Debug for CommitCache<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("CommitCache")
+			.field("commits", &self.commits)
+			.finish_non_exhaustive()
+	}
+}
+
+impl<'a> CommitCache<'a> {
+	/// Returns the key of the commit if its processing result can be cached.
+	///
+	/// The key contains the fields that are set per changelog before the
+	/// processing. Commits with folded fixups are not cached.
+	fn key(commit: &Commit<'a>) -> Option<String> {
+		commit.fixups.is_empty().then(|| {
+			format!(
+				"{}\n{:?}\n{:?}\n{:?}\n{}",
+				commit.id,
+				commit.first_release,
+				commit.reverts,
+				commit.reverted_by,
+				commit.message
+			)
+		})
+	}
+}
+
 /// Changelog generator.
 #[derive(Debug)]
 pub struct Changelog<'a> {
@@ -37,12 +89,24 @@ pub struct Changelog<'a> {
 	footer_template:    Option<Template>,
 	config:             &'a Config,
 	additional_context: HashMap<String, serde_json::Value>,
+	commit_cache:       Option<&'a CommitCache<'a>>,
 }
 
 impl<'a> Changelog<'a> {
 	/// Constructs a new instance.
 	pub fn new(releases: Vec<Release<'a>>, config: &'a Config) -> Result<Self> {
+		Self::new_with_cache(releases, config, None)
+	}
+
+	/// Constructs a new instance that processes the commits via the given
+	/// cache.
+	pub fn new_with_cache(
+		releases: Vec<Release<'a>>,
+		config: &'a Config,
+		commit_cache: Option<&'a CommitCache<'a>>,
+	) -> Result<Self> {
 		let mut changelog = Changelog::build(releases, config)?;
+		changelog.commit_cache = commit_cache;
 		changelog.add_remote_data()?;
 		changelog.process_commits();
 		changelog.process_releases();
@@ -69,6 +133,7 @@ impl<'a> Changelog<'a> {
 			},
 			config,
 			additional_context: HashMap::new(),
+			commit_cache: None,
 		})
 	}
 
@@ -95,11 +160,20 @@ impl<'a> Changelog<'a> {
 	}
 
 	/// Processes a single commit and returns/logs the result.
+	///
+	/// The result is taken from the given cache if it exists.
 	fn process_commit(
 		commit: &Commit<'a>,
 		git_config: &GitConfig,
+		commit_cache: Option<&CommitCache<'a>>,
 	) -> Option<Commit<'a>> {
-		match commit.process(git_config) {
+		let cache_key = commit_cache.and_then(|_| CommitCache::key(commit));
+		if let (Some(cache), Some(key)) = (commit_cache, &cache_key) {
+			if let Some(commit) = cache.commits.borrow().get(key) {
+				return commit.clone();
+			}
+		}
+		let result = match commit.process(git_config) {
 			Ok(commit) => Some(commit),
 			Err(e) => {
 				trace!(
@@ -110,39 +184,230 @@ impl<'a> Changelog<'a> {
 				);
 				None
 			}
+		};
+		if let (Some(cache), Some(key)) = (commit_cache, cache_key) {
+			cache.commits.borrow_mut().insert(key, result.clone());
+		}
+		result
+	}
+
+	/// Drops the commits that are reverted in the same release along with
+	/// their revert commits.
+	///
+	/// If a commit is reverted in another release, both commits are kept and
+	/// linked via [`Commit::reverts`] and [`Commit::reverted_by`].
+	fn cancel_reverts(&mut self) {
+		let find = |id: &str| {
+			self.releases
+				.iter()
+				.enumerate()
+				.find_map(|(release_index, release)| {
+					release
+						.commits
+						.iter()
+						.position(|commit| commit.id.starts_with(id))
+						.map(|commit_index| (release_index, commit_index))
+				})
+		};
+		let mut reverts = self
+			.releases
+			.iter()
+			.enumerate()
+			.flat_map(|(release_index, release)| {
+				release.commits.iter().enumerate().filter_map(
+					move |(commit_index, commit)| {
+						commit.reverted_commit().map(|id| {
+							(
+								commit.committer.timestamp,
+								(release_index, commit_index),
+								id,
+							)
+						})
+					},
+				)
+			})
+			.filter_map(|(timestamp, revert, id)| {
+				find(id).map(|target| (timestamp, revert, target))
+			})
+			.collect::<Vec<_>>();
+		// Handle the latest reverts first so that a reverted revert keeps the
+		// original commit.
+		reverts.sort_by_key(|(timestamp, ..)| std::cmp::Reverse(*timestamp));
+		let mut dropped = HashSet::new();
+		for (_, revert, target) in reverts {
+			if dropped.contains(&revert) || dropped.contains(&target) {
+				continue;
+			}
+			if revert.0 == target.0 {
+				trace!(
+					"{} - Cancelling the reverted commit {}",
+					&self.releases[revert.0].commits[revert.1].id[..7],
+					&self.releases[target.0].commits[target.1].id[..7]
+				);
+				dropped.extend([revert, target]);
+			} else {
+				let revert_id = self.releases[revert.0].commits[revert.1].id.clone();
+				let target_id = self.releases[target.0].commits[target.1].id.clone();
+				self.releases[revert.0].commits[revert.1].reverts = Some(target_id);
+				self.releases[target.0].commits[target.1].reverted_by =
+					Some(revert_id);
+			}
+		}
+		for (release_index, release) in self.releases.iter_mut().enumerate() {
+			let mut commit_index = 0;
+			release.commits.retain(|_| {
+				commit_index += 1;
+				!dropped.contains(&(release_index, commit_index - 1))
+			});
+		}
+	}
+
+	/// Drops the duplicate commits that introduce the same changes.
+	///
+	/// The commits are considered the same if they have the same patch ID or
+	/// one is cherry-picked from the other. Duplicates in the same release are
+	/// dropped, while the ones in the later releases are kept with
+	/// [`Commit::first_release`] set to the version of the first release that
+	/// shipped the change.
+	///
+	/// The commits of each release are visited in chronological order
+	/// regardless of the commit sorting, so that the original commit is kept.
+	fn dedupe_cherry_picks(&mut self) {
+		// Maps the commit IDs and patch IDs to the first release that contains
+		// them.
+		let mut first_releases = HashMap::<String, (usize, Option<String>)>::new();
+		for (release_index, release) in self.releases.iter_mut().enumerate() {
+			let version = release.version.clone();
+			let mut order = (0..release.commits.len()).collect::<Vec<_>>();
+			order.sort_by_key(|&index| {
+				let commit = &release.commits[index];
+				(
+					commit.committer.timestamp,
+					commit.cherry_picked_from().is_some(),
+				)
+			});
+			let mut keep = vec![true; release.commits.len()];
+			for index in order {
+				let commit = &mut release.commits[index];
+				let keys = [
+					(!commit.id.is_empty()).then(|| format!("id:{}", commit.id)),
+					commit.cherry_picked_from().map(|id| format!("id:{id}")),
+					commit.patch_id.as_ref().map(|id| format!("patch:{id}")),
+				];
+				let first_release = keys
+					.iter()
+					.flatten()
+					.find_map(|key| first_releases.get(key))
+					.cloned();
+				if let Some((first_index, first_version)) = &first_release {
+					if *first_index == release_index {
+						trace!(
+							"{} - Dropping the duplicate commit",
+							&commit.id[..7]
+						);
+						keep[index] = false;
+						continue;
+					}
+					commit.first_release.clone_from(first_version);
+				}
+				let first_release =
+					first_release.unwrap_or((release_index, version.clone()));
+				for key in keys.into_iter().flatten() {
+					first_releases.entry(key).or_insert(first_release.clone());
+				}
+			}
+			let mut keep = keep.into_iter();
+			release.commits.retain(|_| keep.next().unwrap_or(true));
 		}
 	}
 
+	/// Folds the `fixup!`, `squash!` and `amend!` commits into the commits
+	/// with the matching subject.
+	///
+	/// Fixup commits without a matching commit in the given commits are kept
+	/// as is.
+	fn collapse_fixups(commits: Vec<Commit<'a>>) -> Vec<Commit<'a>> {
+		let subjects = commits
+			.iter()
+			.filter(|commit| commit.fixup_target().is_none())
+			.filter_map(|commit| commit.message.lines().next())
+			.map(|subject| subject.trim().to_string())
+			.collect::<HashSet<String>>();
+		let (fixups, mut commits): (Vec<Commit>, Vec<Commit>) =
+			commits.into_iter().partition(|commit| {
+				commit
+					.fixup_target()
+					.is_some_and(|target| subjects.contains(target))
+			});
+		for fixup in fixups {
+			let target = fixup.fixup_target().unwrap_or_default().to_string();
+			if let Some(commit) = commits.iter_mut().find(|commit| {
+				commit.fixup_target().is_none() &&
+					commit.message.lines().next().map(str::trim) ==
+						Some(target.as_str())
+			}) {
+				trace!("{} - Folding into {}", &fixup.id[..7], &commit.id[..7]);
+				commit.fixups.push(fixup);
+			}
+		}
+		commits
+	}
+
+	/// Processes the commits of the release and calculates its statistics.
+	fn process_release_commits(
+		release: &mut Release<'a>,
+		config: &GitConfig,
+		commit_cache: Option<&CommitCache<'a>>,
+	) {
+		if config.collapse_fixups.unwrap_or(false) {
+			release.commits = Self::collapse_fixups(release.commits.clone());
+		}
+		release.commits = release
+			.commits
+			.iter()
+			.cloned()
+			.filter_map(|commit| Self::process_commit(&commit, config, commit_cache))
+			.flat_map(|commit| {
+				if config.split_commits.unwrap_or(false) {
+					commit
+						.message
+						.lines()
+						.filter_map(|line| {
+							let mut c = commit.clone();
+							c.message = line.to_string();
+							if c.message.is_empty() {
+								None
+							} else {
+								Self::process_commit(&c, config, commit_cache)
+							}
+						})
+						.collect()
+				} else {
+					vec![commit]
+				}
+			})
+			.collect::<Vec<Commit>>();
+		release.stats = release.calculate_stats();
+		release.breaking_changes = release.collect_breaking_changes();
+	}
+
 	/// Processes the commits and omits the ones that doesn't match the
 	/// criteria set by configuration file.
 	fn process_commits(&mut self) {
 		debug!("Processing the commits...");
+		if self.config.git.cancel_reverts.unwrap_or(false) {
+			self.cancel_reverts();
+		}
+		if self.config.git.dedupe_cherry_picks.unwrap_or(false) {
+			self.dedupe_cherry_picks();
+		}
+		let config = &self.config.git;
+		let commit_cache = self.commit_cache;
 		self.releases.iter_mut().for_each(|release| {
-			release.commits = release
-				.commits
-				.iter()
-				.cloned()
-				.filter_map(|commit| Self::process_commit(&commit, &self.config.git))
-				.flat_map(|commit| {
-					if self.config.git.split_commits.unwrap_or(false) {
-						commit
-							.message
-							.lines()
-							.filter_map(|line| {
-								let mut c = commit.clone();
-								c.message = line.to_string();
-								if c.message.is_empty() {
-									None
-								} else {
-									Self::process_commit(&c, &self.config.git)
-								}
-							})
-							.collect()
-					} else {
-						vec![commit]
-					}
-				})
-				.collect::<Vec<Commit>>();
+			Self::process_release_commits(release, config, commit_cache);
+			release.prereleases.iter_mut().for_each(|prerelease| {
+				Self::process_release_commits(prerelease, config, commit_cache)
+			});
 		});
 	}
 
@@ -438,6 +703,37 @@ impl<'a> Changelog<'a> {
 		Ok(())
 	}
 
+	/// Returns the metadata of the given remote via the commit cache.
+	///
+	/// The metadata is fetched once and shared between the changelogs that
+	/// use the same cache. Empty results are not cached since the metadata is
+	/// only fetched if the templates of the changelog use it.
+	#[cfg(feature = "remote")]
+	fn cached_remote_metadata(
+		&self,
+		provider: &str,
+		remote: &Remote,
+		fetch: fn(&Self) -> Result<RemoteMetadata>,
+	) -> Result<RemoteMetadata> {
+		let key = format!("{provider}\n{remote}");
+		if let Some(metadata) = self
+			.commit_cache
+			.and_then(|cache| cache.remote_metadata.borrow().get(&key).cloned())
+		{
+			return Ok(metadata);
+		}
+		let metadata = fetch(self)?;
+		if let Some(cache) = self.commit_cache {
+			if !metadata.0.is_empty() || !metadata.1.is_empty() {
+				cache
+					.remote_metadata
+					.borrow_mut()
+					.insert(key, metadata.clone());
+			}
+		}
+		Ok(metadata)
+	}
+
 	/// Adds remote data (e.g. GitHub commits) to the releases.
 	pub fn add_remote_data(&mut self) -> Result<()> {
 		debug!("Adding remote data...");
@@ -446,31 +742,47 @@ impl<'a> Changelog<'a> {
 		#[cfg(feature = "github")]
 		let (github_commits, github_pull_requests) = if self.config.remote.github.is_set()
 		{
-			self.get_github_metadata()
-				.expect("Could not get github metadata")
+			self.cached_remote_metadata(
+				"github",
+				&self.config.remote.github,
+				Self::get_github_metadata,
+			)
+			.expect("Could not get github metadata")
 		} else {
 			(vec![], vec![])
 		};
 		#[cfg(feature = "gitlab")]
 		let (gitlab_commits, gitlab_merge_request) = if self.config.remote.gitlab.is_set()
 		{
-			self.get_gitlab_metadata()
-				.expect("Could not get gitlab metadata")
+			self.cached_remote_metadata(
+				"gitlab",
+				&self.config.remote.gitlab,
+				Self::get_gitlab_metadata,
+			)
+			.expect("Could not get gitlab metadata")
 		} else {
 			(vec![], vec![])
 		};
 		#[cfg(feature = "gitea")]
 		let (gitea_commits, gitea_merge_request) = if self.config.remote.gitea.is_set() {
-			self.get_gitea_metadata()
-				.expect("Could not get gitea metadata")
+			self.cached_remote_metadata(
+				"gitea",
+				&self.config.remote.gitea,
+				Self::get_gitea_metadata,
+			)
+			.expect("Could not get gitea metadata")
 		} else {
 			(vec![], vec![])
 		};
 		#[cfg(feature = "bitbucket")]
 		let (bitbucket_commits, bitbucket_pull_request) =
 			if self.config.remote.bitbucket.is_set() {
-				self.get_bitbucket_metadata()
-					.expect("Could not get bitbucket metadata")
+				self.cached_remote_metadata(
+					"bitbucket",
+					&self.config.remote.bitbucket,
+					Self::get_bitbucket_metadata,
+				)
+				.expect("Could not get bitbucket metadata")
 			} else {
 				(vec![], vec![])
 			};
@@ -601,6 +913,36 @@ impl<'a> Changelog<'a> {
 		Ok(())
 	}
 
+	/// Renders the message of the tag for the latest release.
+	///
+	/// The `tag_message` template is used if it is set, otherwise the message
+	/// is rendered with the body template.
+	pub fn generate_tag_message(&self) -> Result<String> {
+		let Some(release) = self.releases.first() else {
+			return Ok(String::new());
+		};
+		let postprocessors = self
+			.config
+			.changelog
+			.postprocessors
+			.clone()
+			.unwrap_or_default();
+		let message = match &self.config.changelog.tag_message {
+			Some(template) => Template::new(
+				"tag_message",
+				template.to_string(),
+				self.config.changelog.trim.unwrap_or(true),
+			)?
+			.render(release, Some(&self.additional_context), &postprocessors)?,
+			None => self.body_template.render(
+				release,
+				Some(&self.additional_context),
+				&postprocessors,
+			)?,
+		};
+		Ok(message.trim().to_string())
+	}
+
 	/// Prints the changelog context to the given output.
 	pub fn write_context<W: Write + ?Sized>(&self, out: &mut W) -> Result<()> {
 		let output = Releases {
@@ -620,16 +962,11 @@ fn get_body_template(config: &Config, trim: bool) -> Result<Template> {
 		.unwrap_or_default()
 		.to_string();
 	let template = Template::new("body", template_str, trim)?;
-	let deprecated_vars = [
-		"commit.github",
-		"commit.gitea",
-		"commit.gitlab",
-		"commit.bitbucket",
-	];
-	if template.contains_variable(&deprecated_vars) {
+	if template.contains_variable(DEPRECATED_VARIABLES) {
 		warn!(
-			"Variables {deprecated_vars:?} are deprecated and will be removed in \
-			 the future. Use `commit.remote` instead."
+			"Variables {DEPRECATED_VARIABLES:?} are deprecated and will be removed \
+			 in the future. Use `commit.remote` instead or run with \
+			 `--migrate-config` to update the configuration file."
 		);
 	}
 	Ok(template)
@@ -638,6 +975,10 @@ fn get_body_template(config: &Config, trim: bool) -> Result<Template> {
 #[cfg(test)]
 mod test {
 	use super::*;
+	use crate::commit::{
+		DiffStats,
+		Signature,
+	};
 	use crate::config::{
 		Bump,
 		ChangelogConfig,
@@ -677,6 +1018,7 @@ mod test {
 				}]),
 				render_always:  None,
 				output:         None,
+				tag_message:    None,
 			},
 			git:       GitConfig {
 				conventional_commits:     Some(true),
@@ -692,149 +1034,173 @@ mod test {
 				}]),
 				commit_parsers:           Some(vec![
 					CommitParser {
-						sha:           Some(String::from("tea")),
-						message:       None,
-						body:          None,
-						footer:        None,
-						group:         Some(String::from("I love tea")),
+						sha: Some(String::from("tea")),
+						message: None,
+						body: None,
+						footer: None,
+						group: Some(String::from("I love tea")),
 						default_scope: None,
-						scope:         None,
-						skip:          None,
-						field:         None,
-						pattern:       None,
+						scope: None,
+						skip: None,
+						field: None,
+						pattern: None,
+						..Default::default()
 					},
 					CommitParser {
-						sha:           Some(String::from("coffee")),
-						message:       None,
-						body:          None,
-						footer:        None,
-						group:         None,
+						sha: Some(String::from("coffee")),
+						message: None,
+						body: None,
+						footer: None,
+						group: None,
 						default_scope: None,
-						scope:         None,
-						skip:          Some(true),
-						field:         None,
-						pattern:       None,
+						scope: None,
+						skip: Some(true),
+						field: None,
+						pattern: None,
+						..Default::default()
 					},
 					CommitParser {
-						sha:           Some(String::from("coffee2")),
-						message:       None,
-						body:          None,
-						footer:        None,
-						group:         None,
+						sha: Some(String::from("coffee2")),
+						message: None,
+						body: None,
+						footer: None,
+						group: None,
 						default_scope: None,
-						scope:         None,
-						skip:          Some(true),
-						field:         None,
-						pattern:       None,
+						scope: None,
+						skip: Some(true),
+						field: None,
+						pattern: None,
+						..Default::default()
 					},
 					CommitParser {
-						sha:           None,
-						message:       Regex::new(r".*merge.*").ok(),
-						body:          None,
-						footer:        None,
-						group:         None,
+						sha: None,
+						message: Regex::new(r".*merge.*").ok(),
+						body: None,
+						footer: None,
+						group: None,
 						default_scope: None,
-						scope:         None,
-						skip:          Some(true),
-						field:         None,
-						pattern:       None,
+						scope: None,
+						skip: Some(true),
+						field: None,
+						pattern: None,
+						..Default::default()
 					},
 					CommitParser {
-						sha:           None,
-						message:       Regex::new("feat*").ok(),
-						body:          None,
-						footer:        None,
-						group:         Some(String::from("New features")),
+						sha: None,
+						message: Regex::new("feat*").ok(),
+						body: None,
+						footer: None,
+						group: Some(String::from("New features")),
 						default_scope: Some(String::from("other")),
-						scope:         None,
-						skip:          None,
-						field:         None,
-						pattern:       None,
+						scope: None,
+						skip: None,
+						field: None,
+						pattern: None,
+						..Default::default()
 					},
 					CommitParser {
-						sha:           None,
-						message:       Regex::new("^fix*").ok(),
-						body:          None,
-						footer:        None,
-						group:         Some(String::from("Bug Fixes")),
+						sha: None,
+						message: Regex::new("^fix*").ok(),
+						body: None,
+						footer: None,
+						group: Some(String::from("Bug Fixes")),
 						default_scope: None,
-						scope:         None,
-						skip:          None,
-						field:         None,
-						pattern:       None,
+						scope: None,
+						skip: None,
+						field: None,
+						pattern: None,
+						..Default::default()
 					},
 					CommitParser {
-						sha:           None,
-						message:       Regex::new("doc:").ok(),
-						body:          None,
-						footer:        None,
-						group:         Some(String::from("Documentation")),
+						sha: None,
+						message: Regex::new("doc:").ok(),
+						body: None,
+						footer: None,
+						group: Some(String::from("Documentation")),
 						default_scope: None,
-						scope:         Some(String::from("documentation")),
-						skip:          None,
-						field:         None,
-						pattern:       None,
+						scope: Some(String::from("documentation")),
+						skip: None,
+						field: None,
+						pattern: None,
+						..Default::default()
 					},
 					CommitParser {
-						sha:           None,
-						message:       Regex::new("docs:").ok(),
-						body:          None,
-						footer:        None,
-						group:         Some(String::from("Documentation")),
+						sha: None,
+						message: Regex::new("docs:").ok(),
+						body: None,
+						footer: None,
+						group: Some(String::from("Documentation")),
 						default_scope: None,
-						scope:         Some(String::from("documentation")),
-						skip:          None,
-						field:         None,
-						pattern:       None,
+						scope: Some(String::from("documentation")),
+						skip: None,
+						field: None,
+						pattern: None,
+						..Default::default()
 					},
 					CommitParser {
-						sha:           None,
-						message:       Regex::new(r"match\((.*)\):.*").ok(),
-						body:          None,
-						footer:        None,
-						group:         Some(String::from("Matched ($1)")),
+						sha: None,
+						message: Regex::new(r"match\((.*)\):.*").ok(),
+						body: None,
+						footer: None,
+						group: Some(String::from("Matched ($1)")),
 						default_scope: None,
-						scope:         None,
-						skip:          None,
-						field:         None,
-						pattern:       None,
+						scope: None,
+						skip: None,
+						field: None,
+						pattern: None,
+						..Default::default()
 					},
 					CommitParser {
-						sha:           None,
-						message:       None,
-						body:          None,
-						footer:        Regex::new("Footer:.*").ok(),
-						group:         Some(String::from("Footer")),
+						sha: None,
+						message: None,
+						body: None,
+						footer: Regex::new("Footer:.*").ok(),
+						group: Some(String::from("Footer")),
 						default_scope: None,
-						scope:         Some(String::from("footer")),
-						skip:          None,
-						field:         None,
-						pattern:       None,
+						scope: Some(String::from("footer")),
+						skip: None,
+						field: None,
+						pattern: None,
+						..Default::default()
 					},
 					CommitParser {
-						sha:           None,
-						message:       Regex::new(".*").ok(),
-						body:          None,
-						footer:        None,
-						group:         Some(String::from("Other")),
+						sha: None,
+						message: Regex::new(".*").ok(),
+						body: None,
+						footer: None,
+						group: Some(String::from("Other")),
 						default_scope: Some(String::from("other")),
-						scope:         None,
-						skip:          None,
-						field:         None,
-						pattern:       None,
+						scope: None,
+						skip: None,
+						field: None,
+						pattern: None,
+						..Default::default()
 					},
 				]),
 				protect_breaking_commits: None,
 				filter_commits:           Some(false),
+				collapse_fixups:          None,
+				cancel_reverts:           None,
+				dedupe_cherry_picks:      None,
+				changelog_trailers:       None,
 				tag_pattern:              None,
+				release_branch_pattern:   None,
 				skip_tags:                Regex::new("v3.*").ok(),
 				ignore_tags:              None,
 				count_tags:               None,
 				use_branch_tags:          Some(false),
 				topo_order:               Some(false),
+				fold_prereleases:         None,
+				tag_sort:                 None,
+				first_parent:             None,
+				since:                    None,
+				until:                    None,
+				date_field:               None,
+				release_period:           None,
 				sort_commits:             Some(String::from("oldest")),
 				link_parsers:             None,
 				limit_commits:            None,
+				mailmap:                  None,
 			},
 			remote:    RemoteConfig {
 				github:    Remote {
@@ -867,10 +1233,14 @@ mod test {
 				},
 			},
 			bump:      Bump::default(),
+			projects:  Vec::new(),
 		};
 		let test_release = Release {
 			version: Some(String::from("v1.0.0")),
 			message: None,
+			stats: DiffStats::default(),
+			breaking_changes: vec![],
+			prereleases: vec![],
 			extra: None,
 			commits: vec![
 				Commit::new(
@@ -968,6 +1338,9 @@ mod test {
 			Release {
 				version: None,
 				message: None,
+				stats: DiffStats::default(),
+				breaking_changes: vec![],
+				prereleases: vec![],
 				extra: None,
 				commits: vec![
 					Commit::new(
@@ -1318,4 +1691,271 @@ chore(deps): fix broken deps
 		.assert_eq(str::from_utf8(&out).unwrap_or_default());
 		Ok(())
 	}
+
+	#[test]
+	fn changelog_collapses_fixups() -> Result<()> {
+		let (mut config, _) = get_test_data();
+		config.git.collapse_fixups = Some(true);
+		let commit = |id: &str, message: &str| {
+			Commit::new(id.repeat(40), String::from(message))
+		};
+		let release = Release {
+			commits: vec![
+				commit("a", "feat(app): add cool features"),
+				commit("b", "fixup! feat(app): add cool features"),
+				commit("c", "fix(app): fix stuff"),
+				commit("d", "squash! fixup! feat(app): add cool features"),
+				commit(
+					"e",
+					"amend! fix(app): fix stuff\n\nfix(app): fix more stuff",
+				),
+				commit("f", "fixup! feat: not in this release"),
+			],
+			..Default::default()
+		};
+		let changelog = Changelog::new(vec![release], &config)?;
+		let commits = &changelog.releases[0].commits;
+		assert_eq!(
+			vec![
+				(&"a".repeat(40), vec!["b".repeat(40), "d".repeat(40)]),
+				(&"c".repeat(40), vec!["e".repeat(40)]),
+				(&"f".repeat(40), vec![]),
+			],
+			commits
+				.iter()
+				.map(|commit| {
+					(
+						&commit.id,
+						commit.fixups.iter().map(|v| v.id.clone()).collect(),
+					)
+				})
+				.collect::<Vec<(&String, Vec<String>)>>()
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn changelog_shares_commit_cache() -> Result<()> {
+		let (config, releases) = get_test_data();
+		let commit_cache = CommitCache::default();
+		let mut outputs = Vec::new();
+		for _ in 0..2 {
+			let changelog = Changelog::new_with_cache(
+				releases.clone(),
+				&config,
+				Some(&commit_cache),
+			)?;
+			let mut out = Vec::new();
+			changelog.generate(&mut out)?;
+			outputs.push(String::from_utf8(out).unwrap_or_default());
+		}
+		let cached_commits = commit_cache.commits.borrow().len();
+		assert!(cached_commits > 0);
+		let changelog = Changelog::new(releases, &config)?;
+		let mut out = Vec::new();
+		changelog.generate(&mut out)?;
+		assert_eq!(vec![String::from_utf8(out).unwrap_or_default(); 2], outputs);
+		assert_eq!(cached_commits, commit_cache.commits.borrow().len());
+		Ok(())
+	}
+
+	#[test]
+	#[cfg(feature = "github")]
+	fn changelog_shares_remote_metadata() -> Result<()> {
+		use crate::remote::github::GitHubCommit;
+		use std::sync::atomic::{
+			AtomicUsize,
+			Ordering,
+		};
+		static FETCHES: AtomicUsize = AtomicUsize::new(0);
+		fn fetch(_: &Changelog) -> Result<RemoteMetadata> {
+			FETCHES.fetch_add(1, Ordering::SeqCst);
+			Ok((
+				vec![Box::new(GitHubCommit {
+					sha: "a".repeat(40),
+					..Default::default()
+				})],
+				vec![],
+			))
+		}
+		let (config, releases) = get_test_data();
+		let commit_cache = CommitCache::default();
+		for _ in 0..2 {
+			let changelog = Changelog::new_with_cache(
+				releases.clone(),
+				&config,
+				Some(&commit_cache),
+			)?;
+			let (commits, _) = changelog.cached_remote_metadata(
+				"github",
+				&config.remote.github,
+				fetch,
+			)?;
+			assert_eq!(1, commits.len());
+		}
+		assert_eq!(1, FETCHES.load(Ordering::SeqCst));
+		Ok(())
+	}
+
+	#[test]
+	fn changelog_tag_message() -> Result<()> {
+		let (mut config, _) = get_test_data();
+		config.changelog.tag_message = Some(String::from(
+			"Release {{ version }}\n\n{{ commits | length }} commit(s)",
+		));
+		let release = Release {
+			commits: vec![
+				Commit::new(
+					"a".repeat(40),
+					String::from("feat(app): add cool features"),
+				),
+				Commit::new("b".repeat(40), String::from("fix(app): fix stuff")),
+			],
+			..Default::default()
+		};
+		let mut changelog = Changelog::new(vec![release], &config)?;
+		assert_eq!(Some(String::from("0.1.0")), changelog.bump_version()?);
+		assert_eq!(
+			"Release 0.1.0\n\n2 commit(s)",
+			changelog.generate_tag_message()?
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn changelog_cancels_reverts() -> Result<()> {
+		let (mut config, _) = get_test_data();
+		config.git.cancel_reverts = Some(true);
+		let commit = |id: &str, message: String, timestamp: i64| Commit {
+			id: id.repeat(40),
+			message,
+			committer: Signature {
+				timestamp,
+				..Default::default()
+			},
+			..Default::default()
+		};
+		let revert = |id: &str, target: &str, timestamp: i64| {
+			commit(
+				id,
+				format!(
+					"revert: revert the commit\n\nThis reverts commit {}.",
+					target.repeat(40)
+				),
+				timestamp,
+			)
+		};
+		let releases = vec![
+			Release {
+				version: Some(String::from("v1.0.0")),
+				commits: vec![
+					commit("a", String::from("feat(app): add cool features"), 1),
+					commit("b", String::from("feat(app): add more features"), 2),
+				],
+				..Default::default()
+			},
+			Release {
+				version: Some(String::from("v1.1.0")),
+				commits: vec![
+					revert("c", "a", 3),
+					commit("d", String::from("feat(app): add experiment"), 4),
+					revert("e", "d", 5),
+					revert("f", "e", 6),
+				],
+				..Default::default()
+			},
+		];
+		let changelog = Changelog::new(releases, &config)?;
+		let ids = |release: &Release| {
+			release
+				.commits
+				.iter()
+				.map(|commit| {
+					(
+						commit.id[..1].to_string(),
+						commit.reverts.as_ref().map(|v| v[..1].to_string()),
+						commit.reverted_by.as_ref().map(|v| v[..1].to_string()),
+					)
+				})
+				.collect::<Vec<_>>()
+		};
+		assert_eq!(
+			vec![
+				(String::from("c"), Some(String::from("a")), None),
+				(String::from("d"), None, None),
+			],
+			ids(&changelog.releases[0])
+		);
+		assert_eq!(
+			vec![
+				(String::from("a"), None, Some(String::from("c"))),
+				(String::from("b"), None, None),
+			],
+			ids(&changelog.releases[1])
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn changelog_dedupes_cherry_picks() -> Result<()> {
+		let (mut config, _) = get_test_data();
+		config.git.dedupe_cherry_picks = Some(true);
+		let commit = |id: &str, message: String, patch_id: Option<&str>| Commit {
+			id: id.repeat(40),
+			message,
+			patch_id: patch_id.map(String::from),
+			..Default::default()
+		};
+		let releases = vec![
+			Release {
+				version: Some(String::from("v1.0.0")),
+				commits: vec![
+					commit("a", String::from("fix(app): fix the crash"), Some("1")),
+					commit("b", String::from("feat(app): add features"), Some("2")),
+				],
+				..Default::default()
+			},
+			Release {
+				version: Some(String::from("v1.1.0")),
+				commits: vec![
+					commit("c", String::from("fix(app): fix the crash"), Some("1")),
+					commit("d", String::from("fix(app): fix the cache"), Some("3")),
+					commit(
+						"e",
+						format!(
+							"fix(app): fix the cache\n\n(cherry picked from commit \
+							 {})",
+							"d".repeat(40)
+						),
+						Some("4"),
+					),
+					commit("f", String::from("feat(app): add more"), None),
+				],
+				..Default::default()
+			},
+		];
+		let changelog = Changelog::new(releases, &config)?;
+		let commits = |release: &Release| {
+			release
+				.commits
+				.iter()
+				.map(|commit| {
+					(commit.id[..1].to_string(), commit.first_release.clone())
+				})
+				.collect::<Vec<_>>()
+		};
+		assert_eq!(
+			vec![
+				(String::from("c"), Some(String::from("v1.0.0"))),
+				(String::from("d"), None),
+				(String::from("f"), None),
+			],
+			commits(&changelog.releases[0])
+		);
+		assert_eq!(
+			vec![(String::from("a"), None), (String::from("b"), None)],
+			commits(&changelog.releases[1])
+		);
+		Ok(())
+	}
 }
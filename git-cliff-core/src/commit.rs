@@ -17,6 +17,7 @@ use git_conventional::{
 	Commit as ConventionalCommit,
 	Footer as ConventionalFooter,
 };
+use glob::Pattern;
 use lazy_regex::{
 	lazy_regex,
 	Lazy,
@@ -32,11 +33,36 @@ use serde::{
 	Serialize,
 };
 use serde_json::value::Value;
+use std::collections::HashSet;
+use std::fmt;
+use std::path::PathBuf;
 
 /// Regular expression for matching SHA1 and a following commit message
 /// separated by a whitespace.
 static SHA1_REGEX: Lazy<Regex> = lazy_regex!(r#"^\b([a-f0-9]{40})\b (.*)$"#);
 
+/// Regular expression for matching a git trailer line, e.g. `Fixes: #123` or
+/// `BREAKING CHANGE: <description>`. Only `BREAKING CHANGE` can also be
+/// followed by ` #` as in the conventional commits.
+static TRAILER_REGEX: Lazy<Regex> = lazy_regex!(
+	r#"^(?:(BREAKING CHANGE) (#)|(BREAKING CHANGE|[A-Za-z0-9][A-Za-z0-9-]*)[ \t]*(:)[ \t]*)(.*)$"#
+);
+
+/// Regular expression for matching the subject of a `fixup!`, `squash!` or
+/// `amend!` commit and capturing the subject of its target commit.
+static FIXUP_REGEX: Lazy<Regex> =
+	lazy_regex!(r#"^(?:(?:fixup|squash|amend)! +)+(.+)$"#);
+
+/// Regular expression for matching the ID of the reverted commit in the
+/// message of a revert commit.
+static REVERT_REGEX: Lazy<Regex> =
+	lazy_regex!(r#"(?m)^This reverts commit ([0-9a-f]{7,40})\b"#);
+
+/// Regular expression for matching the ID of the original commit in the
+/// message of a cherry-picked commit (added by `git cherry-pick -x`).
+static CHERRY_PICK_REGEX: Lazy<Regex> =
+	lazy_regex!(r#"(?m)^\(cherry picked from commit ([0-9a-f]{40})\)"#);
+
 /// Object representing a link
 #[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all(serialize = "camelCase"))]
@@ -76,6 +102,87 @@ impl<'a> From<&'a ConventionalFooter<'a>> for Footer<'a> {
 	}
 }
 
+/// A git trailer, e.g. `Reviewed-by: User Name <user.email@example.com>`.
+///
+/// Unlike [`Footer`], trailers are parsed from every commit message
+/// regardless of whether it is a conventional commit.
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub struct Trailer {
+	/// Token of the trailer (the part preceding the separator).
+	pub token:     String,
+	/// The separator between the trailer token and its value.
+	///
+	/// This is either `:` or `#`.
+	pub separator: String,
+	/// Value of the trailer.
+	///
+	/// Continuation lines are joined with a space.
+	pub value:     String,
+}
+
+impl Trailer {
+	/// Parses the trailers from the given commit message.
+	///
+	/// Trailers are read from the last paragraph of the message (excluding
+	/// the subject) as `git interpret-trailers` does. The lines of the
+	/// paragraph are `token: value` (or `token #value`) pairs, continuation
+	/// (indented) lines or other lines, which are ignored. The paragraph is
+	/// only treated as trailers if at least 25% of its lines are trailers.
+	pub fn parse(message: &str) -> Vec<Self> {
+		let lines = message.trim_end().lines().collect::<Vec<&str>>();
+		let Some(separator) = lines.iter().rposition(|line| line.trim().is_empty())
+		else {
+			return Vec::new();
+		};
+		let mut trailers = Vec::<Self>::new();
+		let mut other_lines = 0;
+		let mut in_trailer = false;
+		for line in &lines[separator + 1..] {
+			if line.starts_with([' ', '\t']) {
+				match trailers.last_mut() {
+					Some(trailer) if in_trailer => {
+						trailer.value.push(' ');
+						trailer.value.push_str(line.trim());
+					}
+					_ => other_lines += 1,
+				}
+			} else if let Some(captures) = TRAILER_REGEX.captures(line) {
+				in_trailer = true;
+				trailers.push(Self {
+					token:     captures
+						.get(1)
+						.or_else(|| captures.get(3))
+						.map(|v| v.as_str().to_string())
+						.unwrap_or_default(),
+					separator: captures
+						.get(2)
+						.or_else(|| captures.get(4))
+						.map(|v| v.as_str().to_string())
+						.unwrap_or_default(),
+					value:     captures[5].trim_end().to_string(),
+				});
+			} else {
+				in_trailer = false;
+				other_lines += 1;
+			}
+		}
+		if trailers.len() * 3 < other_lines {
+			return Vec::new();
+		}
+		trailers
+	}
+}
+
+impl fmt::Display for Trailer {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if self.separator == "#" {
+			write!(f, "{} #{}", self.token, self.value)
+		} else {
+			write!(f, "{}{} {}", self.token, self.separator, self.value)
+		}
+	}
+}
+
 /// Commit signature that indicates authorship.
 #[derive(Debug, Default, Clone, Eq, PartialEq, Deserialize, Serialize)]
 pub struct Signature {
@@ -87,6 +194,27 @@ pub struct Signature {
 	pub timestamp: i64,
 }
 
+impl Signature {
+	/// Parses the signature from the `Name <email>` format.
+	///
+	/// The whole value is used as the name if there is no email.
+	fn parse(value: &str, timestamp: i64) -> Self {
+		let value = value.trim();
+		match value.strip_suffix('>').and_then(|v| v.rsplit_once('<')) {
+			Some((name, email)) => Self {
+				name: Some(name.trim().to_string()).filter(|v| !v.is_empty()),
+				email: Some(email.trim().to_string()),
+				timestamp,
+			},
+			None => Self {
+				name: Some(value.to_string()),
+				email: None,
+				timestamp,
+			},
+		}
+	}
+}
+
 #[cfg(feature = "repo")]
 impl<'a> From<CommitSignature<'a>> for Signature {
 	fn from(signature: CommitSignature<'a>) -> Self {
@@ -98,6 +226,90 @@ impl<'a> From<CommitSignature<'a>> for Signature {
 	}
 }
 
+/// Kind of the change that is made to a file.
+#[derive(
+	Debug,
+	Clone,
+	Copy,
+	Eq,
+	PartialEq,
+	Deserialize,
+	Serialize,
+	bincode::Encode,
+	bincode::Decode,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum FileChangeKind {
+	/// File is added.
+	Added,
+	/// File is modified.
+	Modified,
+	/// File is deleted.
+	Deleted,
+	/// File is renamed (moved).
+	Renamed,
+}
+
+/// File that is changed in a commit.
+#[derive(
+	Debug,
+	Clone,
+	Eq,
+	PartialEq,
+	Deserialize,
+	Serialize,
+	bincode::Encode,
+	bincode::Decode,
+)]
+pub struct FileChange {
+	/// Path of the file.
+	pub path:       PathBuf,
+	/// Previous path of the file if it is renamed.
+	pub old_path:   Option<PathBuf>,
+	/// Kind of the change.
+	pub kind:       FileChangeKind,
+	/// Number of inserted lines.
+	pub insertions: usize,
+	/// Number of deleted lines.
+	pub deletions:  usize,
+}
+
+impl FileChange {
+	/// Returns the paths of the file (including the previous path).
+	pub fn paths(&self) -> impl Iterator<Item = &PathBuf> {
+		std::iter::once(&self.path).chain(self.old_path.as_ref())
+	}
+}
+
+/// Statistics of the changes made to files.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Deserialize, Serialize)]
+pub struct DiffStats {
+	/// Number of changed files.
+	pub files:      usize,
+	/// Number of inserted lines.
+	pub insertions: usize,
+	/// Number of deleted lines.
+	pub deletions:  usize,
+}
+
+impl DiffStats {
+	/// Calculates the statistics of the given file changes.
+	///
+	/// A file that is changed multiple times is counted once.
+	pub fn from_files<'a>(files: impl IntoIterator<Item = &'a FileChange>) -> Self {
+		let mut paths = HashSet::new();
+		let mut stats =
+			files.into_iter().fold(Self::default(), |mut stats, file| {
+				paths.insert(&file.path);
+				stats.insertions += file.insertions;
+				stats.deletions += file.deletions;
+				stats
+			});
+		stats.files = paths.len();
+		stats
+	}
+}
+
 /// Common commit object that is parsed from a repository.
 #[derive(Debug, Default, Clone, PartialEq, Deserialize)]
 #[serde(rename_all(serialize = "camelCase"))]
@@ -124,6 +336,27 @@ pub struct Commit<'a> {
 	pub committer:     Signature,
 	/// Whether if the commit has two or more parents.
 	pub merge_commit:  bool,
+	/// Changelog entry that overrides the rendered message.
+	#[serde(skip_deserializing)]
+	pub entry:         Option<String>,
+	/// Files that are changed in the commit.
+	#[serde(default)]
+	pub files:         Vec<FileChange>,
+	/// `fixup!`, `squash!` and `amend!` commits that are folded into this
+	/// commit.
+	#[serde(default)]
+	pub fixups:        Vec<Commit<'a>>,
+	/// ID of the commit that is reverted by this commit.
+	pub reverts:       Option<String>,
+	/// ID of the commit that reverts this commit.
+	pub reverted_by:   Option<String>,
+	/// Patch ID of the commit, used for detecting the cherry-picked commits.
+	#[serde(skip)]
+	pub patch_id:      Option<String>,
+	/// Version of the release that first shipped the same change, if the
+	/// commit is a duplicate (e.g. cherry-picked) of a commit in an earlier
+	/// release.
+	pub first_release: Option<String>,
 	/// Arbitrary data to be used with the `--from-context` CLI option.
 	pub extra:         Option<Value>,
 	/// Remote metadata of the commit.
@@ -200,6 +433,66 @@ impl Commit<'_> {
 		}
 	}
 
+	/// Returns the subject of the target commit if this is a `fixup!`,
+	/// `squash!` or `amend!` commit.
+	pub fn fixup_target(&self) -> Option<&str> {
+		let subject = self.message.lines().next()?;
+		FIXUP_REGEX
+			.captures(subject.trim())
+			.and_then(|captures| captures.get(1))
+			.map(|v| v.as_str())
+	}
+
+	/// Returns the (possibly abbreviated) ID of the reverted commit if this is
+	/// a revert commit, e.g. `This reverts commit <id>.`
+	pub fn reverted_commit(&self) -> Option<&str> {
+		REVERT_REGEX
+			.captures(&self.message)
+			.and_then(|captures| captures.get(1))
+			.map(|v| v.as_str())
+	}
+
+	/// Returns the ID of the original commit if this commit is cherry-picked
+	/// with `git cherry-pick -x`.
+	pub fn cherry_picked_from(&self) -> Option<&str> {
+		CHERRY_PICK_REGEX
+			.captures(&self.message)
+			.and_then(|captures| captures.get(1))
+			.map(|v| v.as_str())
+	}
+
+	/// Returns the group of the commit, falling back to the conventional
+	/// commit type.
+	pub fn resolved_group(&self) -> Option<&str> {
+		self.group
+			.as_deref()
+			.or_else(|| self.conv.as_ref().map(|conv| conv.type_().as_str()))
+	}
+
+	/// Returns the scope of the commit, falling back to the conventional
+	/// commit scope and the default scope.
+	pub fn resolved_scope(&self) -> Option<&str> {
+		self.scope
+			.as_deref()
+			.or_else(|| {
+				self.conv
+					.as_ref()
+					.and_then(|conv| conv.scope())
+					.map(|scope| scope.as_str())
+			})
+			.or(self.default_scope.as_deref())
+	}
+
+	/// Returns the description of the breaking change.
+	///
+	/// It is taken from the `BREAKING CHANGE` footer, or from the commit
+	/// message if the commit is marked with `!`.
+	pub fn breaking_description(&self) -> Option<&str> {
+		self.conv
+			.as_ref()
+			.and_then(|conv| conv.breaking_description())
+	}
+
 	/// Get raw message for converting into conventional commit.
 	pub fn raw_message(&self) -> &str {
 		self.raw_message.as_deref().unwrap_or(&self.message)
@@ -224,13 +517,25 @@ impl Commit<'_> {
 				commit = conv_commit;
 			}
 		}
+		// The trailers are applied before the parsers so that the commits with
+		// a `Changelog` group are not filtered out.
+		let mut trailer_group = None;
+		if config.changelog_trailers.unwrap_or(false) {
+			commit = commit.apply_changelog_trailers(
+				config.protect_breaking_commits.unwrap_or(false),
+			)?;
+			trailer_group = commit.group.take();
+		}
 		if let Some(parsers) = &config.commit_parsers {
 			commit = commit.parse(
 				parsers,
 				config.protect_breaking_commits.unwrap_or(false),
-				config.filter_commits.unwrap_or(false),
+				config.filter_commits.unwrap_or(false) && trailer_group.is_none(),
 			)?;
 		}
+		if trailer_group.is_some() {
+			commit.group = trailer_group;
+		}
 		if let Some(parsers) = &config.link_parsers {
 			commit = commit.parse_links(parsers)?;
 		}
@@ -275,9 +580,146 @@ impl Commit<'_> {
 				protect_breaking)
 	}
 
+	/// Returns the body and the footers of the commit for matching the
+	/// commit parsers.
+	///
+	/// The footers consist of the conventional footers and the git trailers
+	/// that are not already among them. Both are formatted as trailers (e.g.
+	/// `token: value` or `token #value`) so that the same footer matches the
+	/// same patterns regardless of where it is parsed from.
+	fn parser_texts(&self) -> (Option<String>, Vec<String>) {
+		let body = self
+			.conv
+			.as_ref()
+			.and_then(|v| v.body())
+			.map(|v| v.to_string());
+		let conv_footers = self
+			.conv
+			.iter()
+			.flat_map(|v| v.footers())
+			.map(|footer| Trailer {
+				token:     footer.token().to_string(),
+				separator: footer.separator().trim().to_string(),
+				value:     footer.value().to_string(),
+			})
+			.collect::<Vec<_>>();
+		let normalize = |v: &str| v.split_whitespace().collect::<Vec<_>>().join(" ");
+		let trailers = self.trailers().into_iter().filter(|trailer| {
+			!conv_footers.iter().any(|footer| {
+				footer.token == trailer.token &&
+					normalize(&footer.value) == normalize(&trailer.value)
+			})
+		});
+		let footers = conv_footers
+			.iter()
+			.chain(&trailers.collect::<Vec<_>>())
+			.map(|v| v.to_string())
+			.collect();
+		(body, footers)
+	}
+
+	/// Checks if all the conditions of the given [`CommitParser`] match.
+	///
+	/// `body` and `footers` are the texts returned by [`Self::parser_texts`].
+	/// Returns the regexes of the positive conditions along with the texts
+	/// that they matched, or `None` if the parser does not match.
+	fn match_parser<'p>(
+		&self,
+		parser: &'p CommitParser,
+		lookup_context: &Value,
+		body: Option<&str>,
+		footers: &[String],
+	) -> Result<Option<Vec<(&'p Regex, String)>>> {
+		let has_condition = parser.sha.is_some() ||
+			parser.message.is_some() ||
+			parser.body.is_some() ||
+			parser.footer.is_some() ||
+			(parser.field.is_some() && parser.pattern.is_some()) ||
+			parser.paths.is_some() ||
+			parser.not_message.is_some() ||
+			parser.not_body.is_some() ||
+			parser.not_footer.is_some() ||
+			(parser.not_field.is_some() && parser.not_pattern.is_some());
+		if !has_condition {
+			return Ok(None);
+		}
+		let field_value = |field_name: &str| {
+			if field_name == "body" {
+				body.map(String::from)
+			} else {
+				tera::dotted_pointer(lookup_context, field_name)
+					.map(|v| v.to_string())
+			}
+		};
+		let mut matches = Vec::new();
+		if let Some(sha) = &parser.sha {
+			if sha.to_lowercase() != self.id {
+				return Ok(None);
+			}
+		}
+		if let Some(regex) = &parser.message {
+			if !regex.is_match(self.message.trim()) {
+				return Ok(None);
+			}
+			matches.push((regex, self.message.to_string()));
+		}
+		if let Some(regex) = &parser.body {
+			let body = body.unwrap_or_default();
+			if !regex.is_match(body.trim()) {
+				return Ok(None);
+			}
+			matches.push((regex, body.to_string()));
+		}
+		if let Some(regex) = &parser.footer {
+			match footers.iter().find(|v| regex.is_match(v.trim())) {
+				Some(footer) => matches.push((regex, footer.to_string())),
+				None => return Ok(None),
+			}
+		}
+		if let (Some(field_name), Some(regex)) = (&parser.field, &parser.pattern) {
+			let value = field_value(field_name).ok_or_else(|| {
+				AppError::FieldError(format!(
+					"field {field_name} does not have a value",
+				))
+			})?;
+			if !regex.is_match(value.trim()) {
+				return Ok(None);
+			}
+			matches.push((regex, value));
+		}
+		if let Some(patterns) = &parser.paths {
+			if !self.changes_only(patterns) {
+				return Ok(None);
+			}
+		}
+		let negated_match = parser
+			.not_message
+			.as_ref()
+			.is_some_and(|regex| regex.is_match(self.message.trim())) ||
+			parser.not_body.as_ref().is_some_and(|regex| {
+				regex.is_match(body.unwrap_or_default().trim())
+			}) || parser
+			.not_footer
+			.as_ref()
+			.is_some_and(|regex| footers.iter().any(|v| regex.is_match(v.trim())));
+		if negated_match {
+			return Ok(None);
+		}
+		if let (Some(field_name), Some(regex)) =
+			(&parser.not_field, &parser.not_pattern)
+		{
+			// A field without a value does not match the negated regex.
+			if field_value(field_name).is_some_and(|v| regex.is_match(v.trim())) {
+				return Ok(None);
+			}
+		}
+		Ok(Some(matches))
+	}
+
 	/// Parses the commit using [`CommitParser`]s.
 	///
-	/// Sets the [`group`] and [`scope`] of the commit.
+	/// Sets the [`group`] and [`scope`] of the commit based on the first
+	/// parser whose conditions all match.
 	///
 	/// [`group`]: Commit::group
 	/// [`scope`]: Commit::scope
@@ -292,82 +734,40 @@ impl Commit<'_> {
 				"failed to convert context into value: {e}",
 			))
 		})?;
+		let (body, footers) = self.parser_texts();
 		for parser in parsers {
-			let mut regex_checks = Vec::new();
-			if let Some(message_regex) = parser.message.as_ref() {
-				regex_checks.push((message_regex, self.message.to_string()));
-			}
-			let body = self
-				.conv
-				.as_ref()
-				.and_then(|v| v.body())
-				.map(|v| v.to_string());
-			if let Some(body_regex) = parser.body.as_ref() {
-				regex_checks.push((body_regex, body.clone().unwrap_or_default()));
-			}
-			if let (Some(footer_regex), Some(footers)) = (
-				parser.footer.as_ref(),
-				self.conv.as_ref().map(|v| v.footers()),
-			) {
-				regex_checks
-					.extend(footers.iter().map(|f| (footer_regex, f.to_string())));
-			}
-			if let (Some(field_name), Some(pattern_regex)) =
-				(parser.field.as_ref(), parser.pattern.as_ref())
-			{
-				let value = if field_name == "body" {
-					body.clone()
-				} else {
-					tera::dotted_pointer(&lookup_context, field_name)
-						.map(|v| v.to_string())
-				};
-				match value {
-					Some(value) => {
-						regex_checks.push((pattern_regex, value));
-					}
-					None => {
-						return Err(AppError::FieldError(format!(
-							"field {field_name} does not have a value",
-						)));
-					}
-				}
+			let Some(matches) = self.match_parser(
+				parser,
+				&lookup_context,
+				body.as_deref(),
+				&footers,
+			)?
+			else {
+				continue;
+			};
+			if self.skip_commit(parser, protect_breaking) {
+				return Err(AppError::GroupError(String::from("Skipping commit")));
 			}
-			if parser.sha.clone().map(|v| v.to_lowercase()).as_deref() ==
-				Some(&self.id)
-			{
-				if self.skip_commit(parser, protect_breaking) {
-					return Err(AppError::GroupError(String::from(
-						"Skipping commit",
-					)));
-				} else {
-					self.group = parser.group.clone().or(self.group);
-					self.scope = parser.scope.clone().or(self.scope);
-					self.default_scope =
-						parser.default_scope.clone().or(self.default_scope);
-					return Ok(self);
-				}
-			}
-			for (regex, text) in regex_checks {
-				if regex.is_match(text.trim()) {
-					if self.skip_commit(parser, protect_breaking) {
-						return Err(AppError::GroupError(String::from(
-							"Skipping commit",
-						)));
-					} else {
-						let regex_replace = |mut value: String| {
-							for mat in regex.find_iter(&text) {
-								value =
-									regex.replace(mat.as_str(), value).to_string();
-							}
-							value
-						};
-						self.group = parser.group.clone().map(regex_replace);
-						self.scope = parser.scope.clone().map(regex_replace);
-						self.default_scope.clone_from(&parser.default_scope);
-						return Ok(self);
+			let regex_replace = |mut value: String| {
+				for (regex, text) in &matches {
+					for mat in regex.find_iter(text) {
+						value = regex.replace(mat.as_str(), value).to_string();
 					}
 				}
+				value
+			};
+			if parser.sha.is_some() {
+				// Selecting a specific commit keeps its existing values.
+				self.group = parser.group.clone().map(&regex_replace).or(self.group);
+				self.scope = parser.scope.clone().map(&regex_replace).or(self.scope);
+				self.default_scope =
+					parser.default_scope.clone().or(self.default_scope);
+			} else {
+				self.group = parser.group.clone().map(&regex_replace);
+				self.scope = parser.scope.clone().map(&regex_replace);
+				self.default_scope.clone_from(&parser.default_scope);
 			}
+			return Ok(self);
 		}
 		if filter {
 			Err(AppError::GroupError(String::from(
@@ -378,6 +778,42 @@ impl Commit<'_> {
 		}
 	}
 
+	/// Returns `true` if all the changed files of the commit match one of the
+	/// given patterns.
+	fn changes_only(&self, patterns: &[Pattern]) -> bool {
+		!self.files.is_empty() &&
+			self.files
+				.iter()
+				.all(|file| patterns.iter().any(|p| p.matches_path(&file.path)))
+	}
+
+	/// Applies the `Changelog` and `Changelog-Entry` trailers of the commit.
+	///
+	/// * `Changelog: skip` skips the commit (unless it is a protected breaking
+	///   change)
+	/// * `Changelog: <group>` sets the [`group`]
+	/// * `Changelog-Entry: <text>` overrides the rendered message
+	///
+	/// [`group`]: Commit::group
+	fn apply_changelog_trailers(mut self, protect_breaking: bool) -> Result<Self> {
+		for trailer in self.trailers() {
+			if trailer.token.eq_ignore_ascii_case("Changelog") {
+				if !trailer.value.eq_ignore_ascii_case("skip") {
+					self.group = Some(trailer.value);
+				} else if !(protect_breaking &&
+					self.conv.as_ref().is_some_and(|c| c.breaking()))
+				{
+					return Err(AppError::GroupError(String::from(
+						"Skipping commit",
+					)));
+				}
+			} else if trailer.token.eq_ignore_ascii_case("Changelog-Entry") {
+				self.entry = Some(trailer.value);
+			}
+		}
+		Ok(self)
+	}
+
 	/// Parses the commit using [`LinkParser`]s.
 	///
 	/// Sets the [`links`] of the commit.
@@ -404,6 +840,21 @@ impl Commit<'_> {
 		Ok(self)
 	}
 
+	/// Returns the git [`Trailer`]s of the commit.
+	pub fn trailers(&self) -> Vec<Trailer> {
+		Trailer::parse(self.raw_message())
+	}
+
+	/// Returns the co-authors of the commit from its `Co-authored-by`
+	/// trailers.
+	pub fn coauthors(&self) -> Vec<Signature> {
+		self.trailers()
+			.into_iter()
+			.filter(|trailer| trailer.token.eq_ignore_ascii_case("Co-authored-by"))
+			.map(|trailer| Signature::parse(&trailer.value, self.author.timestamp))
+			.collect()
+	}
+
 	/// Returns an iterator over this commit's [`Footer`]s, if this is a
 	/// conventional commit.
 	///
@@ -437,43 +888,40 @@ impl Serialize for Commit<'_> {
 			}
 		}
 
-		let mut commit = serializer.serialize_struct("Commit", 20)?;
+		let mut commit = serializer.serialize_struct("Commit", 28)?;
 		commit.serialize_field("id", &self.id)?;
 		if let Some(conv) = &self.conv {
-			commit.serialize_field("message", conv.description())?;
-			commit.serialize_field("body", &conv.body())?;
-			commit.serialize_field("footers", &SerializeFooters(self))?;
-			commit.serialize_field(
-				"group",
-				self.group.as_ref().unwrap_or(&conv.type_().to_string()),
-			)?;
 			commit.serialize_field(
-				"breaking_description",
-				&conv.breaking_description(),
-			)?;
-			commit.serialize_field("breaking", &conv.breaking())?;
-			commit.serialize_field(
-				"scope",
-				&self
-					.scope
-					.as_deref()
-					.or_else(|| conv.scope().map(|v| v.as_str()))
-					.or(self.default_scope.as_deref()),
+				"message",
+				self.entry.as_deref().unwrap_or(conv.description()),
 			)?;
+			commit.serialize_field("body", &conv.body())?;
+			commit.serialize_field("footers", &SerializeFooters(self))?;
 		} else {
-			commit.serialize_field("message", &self.message)?;
-			commit.serialize_field("group", &self.group)?;
 			commit.serialize_field(
-				"scope",
-				&self.scope.as_deref().or(self.default_scope.as_deref()),
+				"message",
+				self.entry.as_deref().unwrap_or(&self.message),
 			)?;
 		}
-
+		commit.serialize_field("group", &self.resolved_group())?;
+		commit
+			.serialize_field("breaking_description", &self.breaking_description())?;
+		commit
+			.serialize_field("breaking", &self.breaking_description().is_some())?;
+		commit.serialize_field("scope", &self.resolved_scope())?;
+		commit.serialize_field("trailers", &self.trailers())?;
 		commit.serialize_field("links", &self.links)?;
 		commit.serialize_field("author", &self.author)?;
+		commit.serialize_field("coauthors", &self.coauthors())?;
 		commit.serialize_field("committer", &self.committer)?;
 		commit.serialize_field("conventional", &self.conv.is_some())?;
 		commit.serialize_field("merge_commit", &self.merge_commit)?;
+		commit.serialize_field("files", &self.files)?;
+		commit.serialize_field("fixups", &self.fixups)?;
+		commit.serialize_field("reverts", &self.reverts)?;
+		commit.serialize_field("reverted_by", &self.reverted_by)?;
+		commit.serialize_field("first_release", &self.first_release)?;
+		commit.serialize_field("stats", &DiffStats::from_files(&self.files))?;
 		commit.serialize_field("extra", &self.extra)?;
 		#[cfg(feature = "github")]
 		commit.serialize_field("github", &self.github)?;
@@ -532,16 +980,17 @@ mod test {
 		}
 		let commit = test_cases[0].0.clone().parse(
 			&[CommitParser {
-				sha:           None,
-				message:       Regex::new("test*").ok(),
-				body:          None,
-				footer:        None,
-				group:         Some(String::from("test_group")),
+				sha: None,
+				message: Regex::new("test*").ok(),
+				body: None,
+				footer: None,
+				group: Some(String::from("test_group")),
 				default_scope: Some(String::from("test_scope")),
-				scope:         None,
-				skip:          None,
-				field:         None,
-				pattern:       None,
+				scope: None,
+				skip: None,
+				field: None,
+				pattern: None,
+				..Default::default()
 			}],
 			false,
 			false,
@@ -603,6 +1052,228 @@ mod test {
 		}
 	}
 
+	#[test]
+	fn changelog_trailers() -> Result<()> {
+		let config = GitConfig {
+			conventional_commits: Some(true),
+			filter_unconventional: Some(false),
+			changelog_trailers: Some(true),
+			protect_breaking_commits: Some(true),
+			commit_parsers: Some(vec![CommitParser {
+				message: Regex::new("^feat").ok(),
+				group: Some(String::from("Features")),
+				..Default::default()
+			}]),
+			..Default::default()
+		};
+		let commit = Commit::new(
+			String::from("123123"),
+			String::from(
+				"feat(parser): support trailers\n\nChangelog: \
+				 added\nChangelog-Entry: Legacy commit messages are now supported",
+			),
+		)
+		.process(&config)?;
+		assert_eq!(Some(String::from("added")), commit.group);
+		let value = serde_json::to_value(&commit)?;
+		assert_eq!(
+			Some("Legacy commit messages are now supported"),
+			value["message"].as_str()
+		);
+		assert_eq!(Some("parser"), value["scope"].as_str());
+
+		let commit = Commit::new(
+			String::from("123122"),
+			String::from("feat!: x\n\nBREAKING CHANGE: y\nChangelog: added"),
+		)
+		.process(&config)?;
+		assert_eq!(Some(String::from("added")), commit.group);
+		assert!(commit.conv.as_ref().is_some_and(|c| c.breaking()));
+
+		let commit = Commit::new(
+			String::from("123124"),
+			String::from("feat: add a test\n\nchangelog: skip"),
+		);
+		assert!(commit.process(&config).is_err());
+		let commit = Commit::new(
+			String::from("123125"),
+			String::from("feat!: remove the API\n\nChangelog: skip"),
+		);
+		assert_eq!(
+			Some(String::from("Features")),
+			commit.process(&config)?.group
+		);
+		let filter_config = GitConfig {
+			filter_commits: Some(true),
+			..config.clone()
+		};
+		let commit = Commit::new(
+			String::from("123127"),
+			String::from("docs: update the readme\n\nChangelog: documentation"),
+		);
+		assert_eq!(
+			Some(String::from("documentation")),
+			commit.process(&filter_config)?.group
+		);
+		let commit = Commit::new(
+			String::from("123128"),
+			String::from("docs: update the readme"),
+		);
+		assert!(commit.process(&filter_config).is_err());
+
+		let commit = Commit::new(
+			String::from("123126"),
+			String::from("feat: add a test\n\nChangelog: skip"),
+		);
+		assert!(commit
+			.process(&GitConfig {
+				changelog_trailers: Some(false),
+				..config.clone()
+			})
+			.is_ok());
+		Ok(())
+	}
+
+	#[test]
+	fn parser_footers_without_duplicates() -> Result<()> {
+		let commit =
+			Commit::new(
+				String::from("123123"),
+				String::from(
+					"feat: add the parser\n\nCloses: #1\nBREAKING CHANGE: remove \
+					 the\n  old API",
+				),
+			)
+			.into_conventional()?;
+		assert_eq!(
+			vec![
+				String::from("Closes: #1"),
+				String::from("BREAKING CHANGE: remove the\n  old API"),
+			],
+			commit.parser_texts().1
+		);
+		let commit = Commit::new(
+			String::from("123124"),
+			String::from("Update\n\nCloses: #1"),
+		);
+		assert_eq!(vec![String::from("Closes: #1")], commit.parser_texts().1);
+		Ok(())
+	}
+
+	#[test]
+	fn commit_coauthors() {
+		let commit = Commit {
+			message: String::from(
+				"Pair on the parser\n\nco-authored-by: Jane Doe \
+				 <jane@example.com>\nCo-authored-by: John\nReviewed-by: Orhun \
+				 <orhun@example.com>",
+			),
+			author: Signature {
+				timestamp: 42,
+				..Default::default()
+			},
+			..Default::default()
+		};
+		assert_eq!(
+			vec![
+				Signature {
+					name:      Some(String::from("Jane Doe")),
+					email:     Some(String::from("jane@example.com")),
+					timestamp: 42,
+				},
+				Signature {
+					name:      Some(String::from("John")),
+					email:     None,
+					timestamp: 42,
+				},
+			],
+			commit.coauthors()
+		);
+	}
+
+	#[test]
+	fn commit_trailers() -> Result<()> {
+		let trailer = |token: &str, value: &str| Trailer {
+			token:     token.to_string(),
+			separator: String::from(":"),
+			value:     value.to_string(),
+		};
+		let commit = Commit::new(
+			String::from("123123"),
+			String::from(
+				"Update the parser\n\nSee: the docs\n\nReviewed-by: User \
+				 <user@example.com>\nFixes: #123\nChangelog: Fixed the parser\n  \
+				 for the legacy messages\n",
+			),
+		);
+		assert_eq!(
+			vec![
+				trailer("Reviewed-by", "User <user@example.com>"),
+				trailer("Fixes", "#123"),
+				trailer("Changelog", "Fixed the parser for the legacy messages"),
+			],
+			commit.trailers()
+		);
+		for message in [
+			"Reviewed-by: User",
+			"Update the parser\n\nThis is\nnot a\ntrailer\nblock\nFixes: #123",
+			"Update the parser\n\n  continued: value",
+			"Update the parser\n\nRefs #12",
+		] {
+			assert!(Trailer::parse(message).is_empty());
+		}
+		assert_eq!(
+			vec![
+				Trailer {
+					token:     String::from("BREAKING CHANGE"),
+					separator: String::from("#"),
+					value:     String::from("remove the API"),
+				},
+				trailer("Changelog", "added"),
+			],
+			Trailer::parse(
+				"feat!: remove the API\n\nBREAKING CHANGE #remove the API\nRefs \
+				 #12\nSee the migration guide\n  for the details\nChangelog: added"
+			)
+		);
+		assert_eq!(
+			vec!["BREAKING CHANGE: y", "BREAKING CHANGE #z"],
+			Trailer::parse("feat!: x\n\nBREAKING CHANGE: y\nBREAKING CHANGE #z")
+				.iter()
+				.map(Trailer::to_string)
+				.collect::<Vec<_>>()
+		);
+
+		let parsers = [CommitParser {
+			footer: Regex::new("^Changelog: (.*)").ok(),
+			group: Some(String::from("$1")),
+			..Default::default()
+		}];
+		let config = GitConfig {
+			conventional_commits: Some(false),
+			commit_parsers: Some(parsers.to_vec()),
+			..Default::default()
+		};
+		assert_eq!(
+			Some(String::from("Fixed the parser for the legacy messages")),
+			commit.process(&config)?.group
+		);
+		let commit = Commit::new(
+			String::from("123124"),
+			String::from("fix: update the parser\n\nChangelog: added"),
+		);
+		assert_eq!(
+			Some(String::from("added")),
+			commit
+				.process(&GitConfig {
+					conventional_commits: Some(true),
+					..config
+				})?
+				.group
+		);
+		Ok(())
+	}
+
 	#[test]
 	fn parse_link() -> Result<()> {
 		let test_cases = vec![
@@ -706,16 +1377,17 @@ mod test {
 
 		let parsed_commit = commit.parse(
 			&[CommitParser {
-				sha:           None,
-				message:       None,
-				body:          None,
-				footer:        None,
-				group:         Some(String::from("Test group")),
+				sha: None,
+				message: None,
+				body: None,
+				footer: None,
+				group: Some(String::from("Test group")),
 				default_scope: None,
-				scope:         None,
-				skip:          None,
-				field:         Some(String::from("author.name")),
-				pattern:       Regex::new("John Doe").ok(),
+				scope: None,
+				skip: None,
+				field: Some(String::from("author.name")),
+				pattern: Regex::new("John Doe").ok(),
+				..Default::default()
 			}],
 			false,
 			false,
@@ -733,18 +1405,17 @@ mod test {
 		);
 		let parsed_commit = commit.clone().parse(
 			&[CommitParser {
-				sha:           Some(String::from(
-					"8f55e69eba6e6ce811ace32bd84cc82215673cb6",
-				)),
-				message:       None,
-				body:          None,
-				footer:        None,
-				group:         None,
+				sha: Some(String::from("8f55e69eba6e6ce811ace32bd84cc82215673cb6")),
+				message: None,
+				body: None,
+				footer: None,
+				group: None,
 				default_scope: None,
-				scope:         None,
-				skip:          Some(true),
-				field:         None,
-				pattern:       None,
+				scope: None,
+				skip: Some(true),
+				field: None,
+				pattern: None,
+				..Default::default()
 			}],
 			false,
 			false,
@@ -753,18 +1424,17 @@ mod test {
 
 		let parsed_commit = commit.parse(
 			&[CommitParser {
-				sha:           Some(String::from(
-					"8f55e69eba6e6ce811ace32bd84cc82215673cb6",
-				)),
-				message:       None,
-				body:          None,
-				footer:        None,
-				group:         Some(String::from("Test group")),
+				sha: Some(String::from("8f55e69eba6e6ce811ace32bd84cc82215673cb6")),
+				message: None,
+				body: None,
+				footer: None,
+				group: Some(String::from("Test group")),
 				default_scope: None,
-				scope:         None,
-				skip:          None,
-				field:         None,
-				pattern:       None,
+				scope: None,
+				skip: None,
+				field: None,
+				pattern: None,
+				..Default::default()
 			}],
 			false,
 			false,
@@ -774,6 +1444,105 @@ mod test {
 		Ok(())
 	}
 
+	#[test]
+	fn commit_paths() -> Result<()> {
+		let parsers = [
+			CommitParser {
+				paths: Some(vec![Pattern::new("**/Cargo.lock")?]),
+				skip: Some(true),
+				..Default::default()
+			},
+			CommitParser {
+				paths: Some(vec![Pattern::new("docs/**")?, Pattern::new("*.md")?]),
+				group: Some(String::from("Documentation")),
+				..Default::default()
+			},
+		];
+		let commit = |files: &[&str]| Commit {
+			message: String::from("chore: update"),
+			files: files
+				.iter()
+				.map(|path| FileChange {
+					path:       PathBuf::from(path),
+					old_path:   None,
+					kind:       FileChangeKind::Modified,
+					insertions: 0,
+					deletions:  0,
+				})
+				.collect(),
+			..Default::default()
+		};
+
+		assert!(commit(&["Cargo.lock", "crates/core/Cargo.lock"])
+			.parse(&parsers, false, false)
+			.is_err());
+		assert_eq!(
+			Some(String::from("Documentation")),
+			commit(&["docs/index.md", "README.md"])
+				.parse(&parsers, false, false)?
+				.group
+		);
+		assert_eq!(
+			None,
+			commit(&["docs/index.md", "src/lib.rs"])
+				.parse(&parsers, false, false)?
+				.group
+		);
+		assert_eq!(None, commit(&[]).parse(&parsers, false, false)?.group);
+		Ok(())
+	}
+
+	#[test]
+	fn combine_parser_conditions() -> Result<()> {
+		let parsers = [
+			CommitParser {
+				message: Regex::new("^fix").ok(),
+				field: Some(String::from("scope")),
+				pattern: Regex::new("^\"api\"$").ok(),
+				not_field: Some(String::from("author.name")),
+				not_pattern: Regex::new(r"\[bot\]").ok(),
+				group: Some(String::from("API Fixes")),
+				..Default::default()
+			},
+			CommitParser {
+				not_message: Regex::new("^(fix|chore)").ok(),
+				not_footer: Regex::new("^Internal").ok(),
+				group: Some(String::from("Other")),
+				..Default::default()
+			},
+		];
+		let parse = |message: &str, author: &str| -> Result<Option<String>> {
+			let commit = Commit {
+				message: String::from(message),
+				author: Signature {
+					name: Some(String::from(author)),
+					..Default::default()
+				},
+				..Default::default()
+			};
+			Ok(commit
+				.into_conventional()?
+				.parse(&parsers, false, false)?
+				.group)
+		};
+		assert_eq!(
+			Some(String::from("API Fixes")),
+			parse("fix(api): handle errors", "John Doe")?
+		);
+		assert_eq!(None, parse("fix(api): handle errors", "renovate[bot]")?);
+		assert_eq!(None, parse("fix(ui): handle errors", "John Doe")?);
+		assert_eq!(
+			Some(String::from("Other")),
+			parse("feat(api): add endpoint", "John Doe")?
+		);
+		assert_eq!(
+			None,
+			parse("feat(api): add endpoint\n\nInternal: true", "John Doe")?
+		);
+		assert_eq!(None, parse("chore: update", "John Doe")?);
+		Ok(())
+	}
+
 	#[test]
 	fn field_name_regex() -> Result<()> {
 		let commit = Commit {
@@ -787,16 +1556,17 @@ mod test {
 		};
 		let parsed_commit = commit.clone().parse(
 			&[CommitParser {
-				sha:           None,
-				message:       None,
-				body:          None,
-				footer:        None,
-				group:         Some(String::from("Test group")),
+				sha: None,
+				message: None,
+				body: None,
+				footer: None,
+				group: Some(String::from("Test group")),
 				default_scope: None,
-				scope:         None,
-				skip:          None,
-				field:         Some(String::from("author.name")),
-				pattern:       Regex::new("Something else").ok(),
+				scope: None,
+				skip: None,
+				field: Some(String::from("author.name")),
+				pattern: Regex::new("Something else").ok(),
+				..Default::default()
 			}],
 			false,
 			true,
@@ -806,16 +1576,17 @@ mod test {
 
 		let parsed_commit = commit.parse(
 			&[CommitParser {
-				sha:           None,
-				message:       None,
-				body:          None,
-				footer:        None,
-				group:         Some(String::from("Test group")),
+				sha: None,
+				message: None,
+				body: None,
+				footer: None,
+				group: Some(String::from("Test group")),
 				default_scope: None,
-				scope:         None,
-				skip:          None,
-				field:         Some(String::from("author.name")),
-				pattern:       Regex::new("John Doe").ok(),
+				scope: None,
+				skip: None,
+				field: Some(String::from("author.name")),
+				pattern: Regex::new("John Doe").ok(),
+				..Default::default()
 			}],
 			false,
 			false,
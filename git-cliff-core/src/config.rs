@@ -1,56 +1,134 @@
 use crate::command;
-use crate::error::Result;
-use regex::{
-	Regex,
-	RegexBuilder,
+use crate::embed::BuiltinConfig;
+use crate::error::{
+	Error,
+	Result,
+};
+use glob::Pattern;
+use regex::Regex;
+use schemars::{
+	JsonSchema,
+	SchemaGenerator,
 };
 use secrecy::SecretString;
 use serde::{
 	Deserialize,
 	Serialize,
 };
+use std::collections::HashMap;
+use std::env;
 use std::fmt;
 use std::fs;
 use std::path::Path;
 use std::path::PathBuf;
+use time::format_description::well_known::Rfc3339;
+use time::macros::format_description;
+use time::{
+	Date,
+	OffsetDateTime,
+};
+use yaml_rust2::{
+	Yaml,
+	YamlEmitter,
+};
 
 /// Default initial tag.
 const DEFAULT_INITIAL_TAG: &str = "0.1.0";
 
-/// Manifest file information and regex for matching contents.
+/// Prefix for referring to a built-in configuration in `extends`.
+const BUILTIN_PREFIX: &str = "builtin:";
+
+/// Format of a manifest.
+#[derive(Debug)]
+enum ManifestFormat {
+	/// TOML manifest (e.g. Cargo.toml).
+	Toml,
+	/// JSON manifest (e.g. package.json).
+	Json,
+}
+
+/// Manifest file information and the location of the configuration in it.
 #[derive(Debug)]
 struct ManifestInfo {
 	/// Path of the manifest.
-	path:  PathBuf,
-	/// Regular expression for matching metadata in the manifest.
-	regex: Regex,
+	path:     PathBuf,
+	/// Format of the manifest.
+	format:   ManifestFormat,
+	/// JSON pointers to the possible locations of the configuration.
+	pointers: &'static [&'static str],
+}
+
+impl ManifestInfo {
+	/// Extracts the configuration from the given manifest contents as TOML.
+	///
+	/// Returns `None` if the manifest does not contain a configuration, cannot
+	/// be parsed or its configuration is not a table. The `null` values of
+	/// the configuration are ignored.
+	fn extract(&self, contents: &str) -> Result<Option<String>> {
+		let value: Result<serde_json::Value> = match self.format {
+			ManifestFormat::Toml => toml::from_str(contents).map_err(Error::from),
+			ManifestFormat::Json => {
+				serde_json::from_str(contents).map_err(Error::from)
+			}
+		};
+		let value = match value {
+			Ok(value) => value,
+			Err(e) => {
+				debug!("Skipping {:?} since it cannot be parsed: {e}", self.path);
+				return Ok(None);
+			}
+		};
+		let Some(section) = self.pointers.iter().find_map(|v| value.pointer(v))
+		else {
+			return Ok(None);
+		};
+		match remove_nulls(config::Value::deserialize(section)?) {
+			Some(section) if matches!(section.kind, config::ValueKind::Table(_)) => {
+				let section: toml::Value = section.try_deserialize()?;
+				Ok(Some(toml::to_string(&section)?))
+			}
+			_ => {
+				warn!(
+					"Skipping the configuration in {:?} since it is not a table",
+					self.path
+				);
+				Ok(None)
+			}
+		}
+	}
 }
 
 lazy_static::lazy_static! {
-	/// Array containing manifest information for Rust and Python projects.
+	/// Array containing manifest information for Rust, Python, JavaScript and
+	/// PHP projects.
 	static ref MANIFEST_INFO: Vec<ManifestInfo> = vec![
 		ManifestInfo {
 			path: PathBuf::from("Cargo.toml"),
-			regex: RegexBuilder::new(
-				r"^\[(?:workspace|package)\.metadata\.git\-cliff\.",
-			)
-			.multi_line(true)
-			.build()
-			.expect("failed to build regex"),
+			format: ManifestFormat::Toml,
+			pointers: &["/package/metadata/git-cliff", "/workspace/metadata/git-cliff"],
 		},
 		ManifestInfo {
 			path: PathBuf::from("pyproject.toml"),
-			regex: RegexBuilder::new(r"^\[(?:tool)\.git\-cliff\.")
-				.multi_line(true)
-				.build()
-				.expect("failed to build regex"),
+			format: ManifestFormat::Toml,
+			pointers: &["/tool/git-cliff"],
+		},
+		ManifestInfo {
+			path: PathBuf::from("package.json"),
+			format: ManifestFormat::Json,
+			pointers: &["/git-cliff"],
+		},
+		ManifestInfo {
+			path: PathBuf::from("composer.json"),
+			format: ManifestFormat::Json,
+			pointers: &["/extra/git-cliff"],
 		},
 	];
 
 }
 
 /// Configuration values.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(deny_unknown_fields)]
 pub struct Config {
 	/// Configuration values about changelog generation.
 	#[serde(default)]
@@ -64,10 +142,14 @@ pub struct Config {
 	/// Configuration values about bump version.
 	#[serde(default)]
 	pub bump:      Bump,
+	/// Projects to generate separate changelogs for.
+	#[serde(default, rename = "project")]
+	pub projects:  Vec<ProjectConfig>,
 }
 
 /// Changelog configuration.
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(deny_unknown_fields)]
 pub struct ChangelogConfig {
 	/// Changelog header.
 	pub header:         Option<String>,
@@ -83,10 +165,58 @@ pub struct ChangelogConfig {
 	pub postprocessors: Option<Vec<TextProcessor>>,
 	/// Output file path.
 	pub output:         Option<PathBuf>,
+	/// Template for the message of the created release tag.
+	pub tag_message:    Option<String>,
+}
+
+/// Project configuration.
+///
+/// Each project produces a separate changelog that contains only the commits
+/// which change the project paths.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct ProjectConfig {
+	/// Name of the project.
+	pub name:          String,
+	/// Glob patterns of the paths to include.
+	pub include_paths: Option<Vec<String>>,
+	/// Glob patterns of the paths to exclude.
+	pub exclude_paths: Option<Vec<String>>,
+	/// Regex to select the tags of the project.
+	#[serde(with = "serde_regex", default)]
+	#[schemars(with = "Option<String>")]
+	pub tag_pattern:   Option<Regex>,
+	/// Output file path.
+	pub output:        Option<PathBuf>,
+	/// Changelog header override.
+	pub header:        Option<String>,
+	/// Changelog body override.
+	pub body:          Option<String>,
+	/// Changelog footer override.
+	pub footer:        Option<String>,
+}
+
+impl ProjectConfig {
+	/// Applies the project specific values to the given configuration.
+	pub fn apply(&self, config: &mut Config) {
+		if self.tag_pattern.is_some() {
+			config.git.tag_pattern.clone_from(&self.tag_pattern);
+		}
+		for (value, project_value) in [
+			(&mut config.changelog.header, &self.header),
+			(&mut config.changelog.body, &self.body),
+			(&mut config.changelog.footer, &self.footer),
+		] {
+			if project_value.is_some() {
+				value.clone_from(project_value);
+			}
+		}
+	}
 }
 
 /// Git configuration
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(deny_unknown_fields)]
 pub struct GitConfig {
 	/// Whether to enable parsing conventional commits.
 	pub conventional_commits:  Option<bool>,
@@ -107,30 +237,96 @@ pub struct GitConfig {
 	pub link_parsers:             Option<Vec<LinkParser>>,
 	/// Whether to filter out commits.
 	pub filter_commits:           Option<bool>,
+	/// Whether to fold the `fixup!`, `squash!` and `amend!` commits into their
+	/// target commits.
+	pub collapse_fixups:          Option<bool>,
+	/// Whether to drop the commits that are reverted in the same release along
+	/// with their revert commits.
+	pub cancel_reverts:           Option<bool>,
+	/// Whether to drop the duplicate (e.g. cherry-picked) commits based on
+	/// their patch IDs.
+	pub dedupe_cherry_picks:      Option<bool>,
+	/// Whether to use the `Changelog` and `Changelog-Entry` trailers of the
+	/// commits for overriding their groups and messages.
+	pub changelog_trailers:       Option<bool>,
 	/// Blob pattern for git tags.
 	#[serde(with = "serde_regex", default)]
+	#[schemars(with = "Option<String>")]
 	pub tag_pattern:              Option<Regex>,
+	/// Regex for the release branches that are used as virtual tags.
+	#[serde(with = "serde_regex", default)]
+	#[schemars(with = "Option<String>")]
+	pub release_branch_pattern:   Option<Regex>,
 	/// Regex to skip matched tags.
 	#[serde(with = "serde_regex", default)]
+	#[schemars(with = "Option<String>")]
 	pub skip_tags:                Option<Regex>,
 	/// Regex to ignore matched tags.
 	#[serde(with = "serde_regex", default)]
+	#[schemars(with = "Option<String>")]
 	pub ignore_tags:              Option<Regex>,
 	/// Regex to count matched tags.
 	#[serde(with = "serde_regex", default)]
+	#[schemars(with = "Option<String>")]
 	pub count_tags:               Option<Regex>,
 	/// Include only the tags that belong to the current branch.
 	pub use_branch_tags:          Option<bool>,
 	/// Whether to sort tags topologically.
 	pub topo_order:               Option<bool>,
+	/// Whether to fold the pre-releases into their final releases.
+	pub fold_prereleases:         Option<bool>,
+	/// Sorting of the tags.
+	pub tag_sort:                 Option<TagSort>,
+	/// Whether to follow only the first parent of the merge commits.
+	pub first_parent:             Option<bool>,
+	/// Include only the commits made on or after this date.
+	pub since:                    Option<String>,
+	/// Include only the commits made on or before this date.
+	pub until:                    Option<String>,
+	/// Date of the commits that is used for filtering and grouping.
+	pub date_field:               Option<DateField>,
+	/// Calendar period for splitting the releases instead of tags.
+	pub release_period:           Option<ReleasePeriod>,
 	/// Sorting of the commits inside sections.
 	pub sort_commits:             Option<String>,
 	/// Limit the number of commits included in the changelog.
 	pub limit_commits:            Option<usize>,
+	/// Path of the mailmap file that is used in addition to `.mailmap`.
+	pub mailmap:                  Option<PathBuf>,
+}
+
+impl GitConfig {
+	/// Returns the inclusive range of the commit timestamps from `since` and
+	/// `until`.
+	///
+	/// The dates are either in `YYYY-MM-DD` (UTC) or RFC 3339 format. The dates
+	/// without time include the whole day.
+	pub fn time_range(&self) -> Result<(Option<i64>, Option<i64>)> {
+		let parse = |value: &str, end_of_day: bool| -> Result<i64> {
+			match Date::parse(value, format_description!("[year]-[month]-[day]")) {
+				Ok(date) => {
+					let timestamp = date.midnight().assume_utc().unix_timestamp();
+					Ok(if end_of_day {
+						timestamp + 24 * 60 * 60 - 1
+					} else {
+						timestamp
+					})
+				}
+				Err(_) => {
+					Ok(OffsetDateTime::parse(value, &Rfc3339)?.unix_timestamp())
+				}
+			}
+		};
+		Ok((
+			self.since.as_deref().map(|v| parse(v, false)).transpose()?,
+			self.until.as_deref().map(|v| parse(v, true)).transpose()?,
+		))
+	}
 }
 
 /// Remote configuration.
-#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(deny_unknown_fields)]
 pub struct RemoteConfig {
 	/// GitHub remote.
 	#[serde(default)]
@@ -170,7 +366,8 @@ impl RemoteConfig {
 }
 
 /// A single remote.
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(deny_unknown_fields)]
 pub struct Remote {
 	/// Owner of the remote.
 	pub owner:     String,
@@ -178,9 +375,11 @@ pub struct Remote {
 	pub repo:      String,
 	/// Access token.
 	#[serde(skip_serializing)]
+	#[schemars(with = "Option<String>")]
 	pub token:     Option<SecretString>,
 	/// Whether if the remote is set manually.
 	#[serde(skip_deserializing, default = "default_true")]
+	#[schemars(skip)]
 	pub is_custom: bool,
 	/// Remote API URL.
 	pub api_url:   Option<String>,
@@ -221,8 +420,62 @@ impl Remote {
 	}
 }
 
+/// Sorting of the tags.
+#[derive(
+	Debug, Default, Clone, Copy, Serialize, Deserialize, Eq, PartialEq, JsonSchema,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum TagSort {
+	/// Sort by the time of the tagged commits.
+	#[default]
+	Time,
+	/// Sort topologically.
+	Topological,
+	/// Sort by the semantic versions.
+	Semver,
+}
+
+/// Date of the commits.
+#[derive(
+	Debug, Default, Clone, Copy, Serialize, Deserialize, Eq, PartialEq, JsonSchema,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum DateField {
+	/// Committer date.
+	#[default]
+	Committer,
+	/// Author date.
+	Author,
+}
+
+/// Calendar period of the releases.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ReleasePeriod {
+	/// Weekly releases, e.g. `2024-W37`.
+	Weekly,
+	/// Monthly releases, e.g. `2024-09`.
+	Monthly,
+}
+
+impl ReleasePeriod {
+	/// Returns the name of the period that contains the given timestamp.
+	pub fn name(&self, timestamp: i64) -> Result<String> {
+		let date = OffsetDateTime::from_unix_timestamp(timestamp)?.date();
+		Ok(match self {
+			Self::Weekly => {
+				let (year, week, _) = date.to_iso_week_date();
+				format!("{year}-W{week:02}")
+			}
+			Self::Monthly => {
+				format!("{}-{:02}", date.year(), u8::from(date.month()))
+			}
+		})
+	}
+}
+
 /// Version bump type.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq, JsonSchema)]
 pub enum BumpType {
 	/// Bump major version.
 	Major,
@@ -233,7 +486,8 @@ pub enum BumpType {
 }
 
 /// Bump version configuration.
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(deny_unknown_fields)]
 pub struct Bump {
 	/// Configures automatic minor version increments for feature changes.
 	///
@@ -279,6 +533,40 @@ pub struct Bump {
 
 	/// Force to always bump in major, minor or patch.
 	pub bump_type: Option<BumpType>,
+
+	/// Files to write the bumped version into.
+	///
+	/// Each file is updated either by a known manifest format or by a
+	/// regex pattern containing the `{version}` placeholder.
+	pub version_files: Option<Vec<VersionFile>>,
+}
+
+/// File that contains the version of the project.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct VersionFile {
+	/// Path of the file.
+	pub path:    PathBuf,
+	/// Format of the file.
+	///
+	/// Inferred from the file name if neither `format` nor `pattern` is set.
+	pub format:  Option<VersionFileFormat>,
+	/// Regex for matching the version with the `{version}` placeholder.
+	pub pattern: Option<String>,
+}
+
+/// Known formats of the version files.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum VersionFileFormat {
+	/// `package.version` of a `Cargo.toml` file.
+	Cargo,
+	/// Root `Cargo.toml` of a workspace along with its members.
+	CargoWorkspace,
+	/// `version` of a `package.json` file.
+	PackageJson,
+	/// `project.version` or `tool.poetry.version` of a `pyproject.toml` file.
+	Pyproject,
 }
 
 impl Bump {
@@ -302,18 +590,24 @@ impl Bump {
 }
 
 /// Parser for grouping commits.
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+///
+/// A parser matches a commit if all of its conditions match.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(deny_unknown_fields)]
 pub struct CommitParser {
 	/// SHA1 of the commit.
 	pub sha:           Option<String>,
 	/// Regex for matching the commit message.
 	#[serde(with = "serde_regex", default)]
+	#[schemars(with = "Option<String>")]
 	pub message:       Option<Regex>,
 	/// Regex for matching the commit body.
 	#[serde(with = "serde_regex", default)]
+	#[schemars(with = "Option<String>")]
 	pub body:          Option<Regex>,
 	/// Regex for matching the commit footer.
 	#[serde(with = "serde_regex", default)]
+	#[schemars(with = "Option<String>")]
 	pub footer:        Option<Regex>,
 	/// Group of the commit.
 	pub group:         Option<String>,
@@ -327,14 +621,77 @@ pub struct CommitParser {
 	pub field:         Option<String>,
 	/// Regex for matching the field value.
 	#[serde(with = "serde_regex", default)]
+	#[schemars(with = "Option<String>")]
 	pub pattern:       Option<Regex>,
+	/// Glob patterns for matching the changed files.
+	///
+	/// Matches if all the files changed in the commit match one of the
+	/// patterns.
+	#[serde(with = "serde_patterns", default)]
+	#[schemars(with = "Option<Vec<String>>")]
+	pub paths:         Option<Vec<Pattern>>,
+	/// Regex that the commit message must not match.
+	#[serde(with = "serde_regex", default)]
+	#[schemars(with = "Option<String>")]
+	pub not_message:   Option<Regex>,
+	/// Regex that the commit body must not match.
+	#[serde(with = "serde_regex", default)]
+	#[schemars(with = "Option<String>")]
+	pub not_body:      Option<Regex>,
+	/// Regex that none of the commit footers must match.
+	#[serde(with = "serde_regex", default)]
+	#[schemars(with = "Option<String>")]
+	pub not_footer:    Option<Regex>,
+	/// Field name of the commit to match the negated regex against.
+	pub not_field:     Option<String>,
+	/// Regex that the value of `not_field` must not match.
+	#[serde(with = "serde_regex", default)]
+	#[schemars(with = "Option<String>")]
+	pub not_pattern:   Option<Regex>,
+}
+
+/// Serialization of optional glob patterns as strings.
+mod serde_patterns {
+	use glob::Pattern;
+	use serde::de::Error;
+	use serde::{
+		Deserialize,
+		Deserializer,
+		Serialize,
+		Serializer,
+	};
+
+	pub fn serialize<S: Serializer>(
+		patterns: &Option<Vec<Pattern>>,
+		serializer: S,
+	) -> Result<S::Ok, S::Error> {
+		patterns
+			.as_ref()
+			.map(|v| v.iter().map(Pattern::as_str).collect::<Vec<&str>>())
+			.serialize(serializer)
+	}
+
+	pub fn deserialize<'de, D: Deserializer<'de>>(
+		deserializer: D,
+	) -> Result<Option<Vec<Pattern>>, D::Error> {
+		Option::<Vec<String>>::deserialize(deserializer)?
+			.map(|patterns| {
+				patterns
+					.iter()
+					.map(|v| Pattern::new(v).map_err(D::Error::custom))
+					.collect()
+			})
+			.transpose()
+	}
 }
 
 /// `TextProcessor`, e.g. for modifying commit messages.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(deny_unknown_fields)]
 pub struct TextProcessor {
 	/// Regex for matching a text to replace.
 	#[serde(with = "serde_regex")]
+	#[schemars(with = "String")]
 	pub pattern:         Regex,
 	/// Replacement text.
 	pub replace:         Option<String>,
@@ -362,10 +719,12 @@ impl TextProcessor {
 }
 
 /// Parser for extracting links in commits.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(deny_unknown_fields)]
 pub struct LinkParser {
 	/// Regex for finding links in the commit message.
 	#[serde(with = "serde_regex")]
+	#[schemars(with = "String")]
 	pub pattern: Regex,
 	/// The string used to generate the link URL.
 	pub href:    String,
@@ -373,58 +732,404 @@ pub struct LinkParser {
 	pub text:    Option<String>,
 }
 
+/// Strategy for merging an array with the inherited one while resolving
+/// `extends`.
+#[derive(
+	Debug, Default, Clone, Copy, Serialize, Deserialize, Eq, PartialEq, JsonSchema,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum MergeStrategy {
+	/// Replace the inherited array.
+	#[default]
+	Replace,
+	/// Insert the entries before the inherited ones.
+	Prepend,
+	/// Insert the entries after the inherited ones.
+	Append,
+}
+
+/// Format of the configuration file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFormat {
+	/// TOML format.
+	Toml,
+	/// YAML format.
+	Yaml,
+	/// JSON format.
+	Json,
+}
+
+impl ConfigFormat {
+	/// Returns the format of the given config file based on its extension.
+	pub fn from_path(path: &Path) -> Option<Self> {
+		match path.extension()?.to_str()? {
+			"toml" => Some(Self::Toml),
+			"yaml" | "yml" => Some(Self::Yaml),
+			"json" => Some(Self::Json),
+			_ => None,
+		}
+	}
+
+	/// Parses the given contents into a TOML value.
+	fn parse(self, contents: &str) -> Result<toml::Value> {
+		let format = match self {
+			Self::Toml => return Ok(toml::from_str(contents)?),
+			Self::Yaml => config::FileFormat::Yaml,
+			Self::Json => config::FileFormat::Json,
+		};
+		let value: config::Value = config::Config::builder()
+			.add_source(config::File::from_str(contents, format))
+			.build()?
+			.try_deserialize()?;
+		match remove_nulls(value) {
+			Some(value) => Ok(value.try_deserialize()?),
+			None => Ok(toml::Value::Table(toml::Table::new())),
+		}
+	}
+
+	/// Converts the given TOML contents into this format.
+	pub fn convert(self, contents: &str) -> Result<String> {
+		match self {
+			Self::Toml => Ok(contents.to_string()),
+			Self::Yaml => {
+				let value: toml::Value = toml::from_str(contents)?;
+				let mut output = String::new();
+				YamlEmitter::new(&mut output).dump(&toml_to_yaml(value))?;
+				Ok(output + "\n")
+			}
+			Self::Json => {
+				let value: toml::Value = toml::from_str(contents)?;
+				Ok(serde_json::to_string_pretty(&value)? + "\n")
+			}
+		}
+	}
+}
+
+/// Removes the `null` values recursively since they cannot be represented in
+/// TOML.
+///
+/// Returns `None` if the value itself is `null`.
+fn remove_nulls(value: config::Value) -> Option<config::Value> {
+	let origin = value.origin().map(String::from);
+	let kind = match value.kind {
+		config::ValueKind::Nil => return None,
+		config::ValueKind::Table(table) => config::ValueKind::Table(
+			table
+				.into_iter()
+				.filter_map(|(key, value)| Some((key, remove_nulls(value)?)))
+				.collect(),
+		),
+		config::ValueKind::Array(values) => config::ValueKind::Array(
+			values.into_iter().filter_map(remove_nulls).collect(),
+		),
+		kind => kind,
+	};
+	Some(config::Value::new(origin.as_ref(), kind))
+}
+
+/// Converts the given TOML value into a YAML node.
+fn toml_to_yaml(value: toml::Value) -> Yaml {
+	match value {
+		toml::Value::String(v) => Yaml::String(v),
+		toml::Value::Integer(v) => Yaml::Integer(v),
+		toml::Value::Float(v) => Yaml::Real(format!("{v:?}")),
+		toml::Value::Boolean(v) => Yaml::Boolean(v),
+		toml::Value::Datetime(v) => Yaml::String(v.to_string()),
+		toml::Value::Array(v) => {
+			Yaml::Array(v.into_iter().map(toml_to_yaml).collect())
+		}
+		toml::Value::Table(v) => Yaml::Hash(
+			v.into_iter()
+				.map(|(key, value)| (Yaml::String(key), toml_to_yaml(value)))
+				.collect(),
+		),
+	}
+}
+
 impl Config {
 	/// Reads the config file contents from project manifest (e.g. Cargo.toml,
-	/// pyproject.toml)
+	/// pyproject.toml, package.json, composer.json)
 	pub fn read_from_manifest() -> Result<Option<String>> {
 		for info in &(*MANIFEST_INFO) {
 			if info.path.exists() {
 				let contents = fs::read_to_string(&info.path)?;
-				if info.regex.is_match(&contents) {
-					return Ok(Some(
-						info.regex.replace_all(&contents, "[").to_string(),
-					));
+				if let Some(contents) = info.extract(&contents)? {
+					return Ok(Some(contents));
 				}
 			}
 		}
 		Ok(None)
 	}
 
+	/// Reads the contents of the config file as TOML.
+	///
+	/// For project manifests, only the configuration section is returned.
+	/// YAML and JSON files are converted to TOML.
+	pub fn read_contents(path: &Path) -> Result<String> {
+		if MANIFEST_INFO
+			.iter()
+			.any(|v| path.file_name() == v.path.file_name())
+		{
+			if let Some(contents) = Self::read_from_manifest()? {
+				return Ok(contents);
+			}
+		}
+		let contents = fs::read_to_string(path)?;
+		match ConfigFormat::from_path(path) {
+			Some(ConfigFormat::Toml) | None => Ok(contents),
+			Some(format) => Ok(toml::to_string(&format.parse(&contents)?)?),
+		}
+	}
+
 	/// Parses the config file from string and returns the values.
+	///
+	/// Relative paths in `extends` are resolved from the current directory.
+	/// Unknown keys are reported as warnings.
 	pub fn parse_from_str(contents: &str) -> Result<Config> {
-		Ok(config::Config::builder()
-			.add_source(config::File::from_str(contents, config::FileFormat::Toml))
-			.add_source(
-				config::Environment::with_prefix("GIT_CLIFF").separator("__"),
-			)
-			.build()?
-			.try_deserialize()?)
+		Self::parse_from_str_with(contents, false)
+	}
+
+	/// Same as [`Config::parse_from_str`] but returns an error for unknown
+	/// keys.
+	pub fn parse_from_str_strict(contents: &str) -> Result<Config> {
+		Self::parse_from_str_with(contents, true)
 	}
 
 	/// Parses the config file and returns the values.
+	///
+	/// Relative paths in `extends` are resolved from the directory of the
+	/// config file. Unknown keys are reported as warnings.
 	pub fn parse(path: &Path) -> Result<Config> {
+		Self::parse_with(path, false)
+	}
+
+	/// Same as [`Config::parse`] but returns an error for unknown keys.
+	pub fn parse_strict(path: &Path) -> Result<Config> {
+		Self::parse_with(path, true)
+	}
+
+	/// Parses the config file from string.
+	fn parse_from_str_with(contents: &str, strict: bool) -> Result<Config> {
+		let contents = Self::resolve_extends(contents, &env::current_dir()?)?;
+		Self::deserialize(
+			config::File::from_str(&contents, config::FileFormat::Toml),
+			strict,
+		)
+	}
+
+	/// Parses the config file at the given path.
+	fn parse_with(path: &Path, strict: bool) -> Result<Config> {
 		if MANIFEST_INFO
 			.iter()
 			.any(|v| path.file_name() == v.path.file_name())
 		{
 			if let Some(contents) = Self::read_from_manifest()? {
-				return Self::parse_from_str(&contents);
+				return Self::parse_from_str_with(&contents, strict);
 			}
 		}
 
-		Ok(config::Config::builder()
-			.add_source(config::File::from(path))
+		if let Some(format) = ConfigFormat::from_path(path) {
+			let base_dir = path.parent().unwrap_or(Path::new(""));
+			let value = Self::resolve_value(
+				format.parse(&fs::read_to_string(path)?)?,
+				base_dir,
+				&mut Vec::new(),
+			)?;
+			return Self::deserialize(
+				config::File::from_str(
+					&toml::to_string(&value)?,
+					config::FileFormat::Toml,
+				),
+				strict,
+			);
+		}
+
+		Self::deserialize(config::File::from(path), strict)
+	}
+
+	/// Deserializes the configuration from the given source and the
+	/// environment variables.
+	///
+	/// Unknown keys are either logged or returned as an error if `strict` is
+	/// set.
+	fn deserialize<S>(source: S, strict: bool) -> Result<Config>
+	where
+		S: config::Source + Send + Sync + 'static,
+	{
+		let config = config::Config::builder()
+			.add_source(source)
 			.add_source(
 				config::Environment::with_prefix("GIT_CLIFF").separator("__"),
 			)
-			.build()?
-			.try_deserialize()?)
+			.build()?;
+		let mut unknown_keys = Vec::new();
+		let config: Config = serde_ignored::deserialize(config, |path| {
+			unknown_keys.push(path.to_string());
+		})?;
+		if strict && !unknown_keys.is_empty() {
+			return Err(config::ConfigError::Message(format!(
+				"unknown configuration keys: {}",
+				unknown_keys.join(", ")
+			))
+			.into());
+		}
+		for key in unknown_keys {
+			warn!("Unknown configuration key: {key}");
+		}
+		Ok(config)
+	}
+
+	/// Returns the JSON schema of the configuration file.
+	pub fn json_schema() -> Result<String> {
+		let mut generator = SchemaGenerator::default();
+		let extends = generator.subschema_for::<Option<Vec<String>>>();
+		let merge =
+			generator.subschema_for::<Option<HashMap<String, MergeStrategy>>>();
+		let mut schema = generator.root_schema_for::<Config>();
+		if let Some(properties) = schema
+			.get_mut("properties")
+			.and_then(serde_json::Value::as_object_mut)
+		{
+			properties.insert(String::from("extends"), extends.to_value());
+			properties.insert(String::from("merge"), merge.to_value());
+		}
+		Ok(serde_json::to_string_pretty(&schema)?)
+	}
+
+	/// Resolves the configurations listed in `extends` and merges them with
+	/// the given contents.
+	///
+	/// Returns the contents as is if there is nothing to extend.
+	pub(crate) fn resolve_extends(
+		contents: &str,
+		base_dir: &Path,
+	) -> Result<String> {
+		let value: toml::Value = toml::from_str(contents)?;
+		if value.get("extends").is_none() && value.get("merge").is_none() {
+			return Ok(contents.to_string());
+		}
+		let value = Self::resolve_value(value, base_dir, &mut Vec::new())?;
+		Ok(toml::to_string(&value)?)
+	}
+
+	/// Recursively resolves `extends` of the given value.
+	///
+	/// Entries that start with `builtin:` are loaded via [`BuiltinConfig`] and
+	/// the rest are treated as paths relative to `base_dir`. `stack` holds the
+	/// configurations that are currently being resolved for detecting cycles.
+	fn resolve_value(
+		value: toml::Value,
+		base_dir: &Path,
+		stack: &mut Vec<String>,
+	) -> Result<toml::Value> {
+		let toml::Value::Table(mut table) = value else {
+			return Ok(value);
+		};
+		let extends = match table.remove("extends") {
+			Some(toml::Value::String(v)) => vec![v],
+			Some(v) => v.try_into::<Vec<String>>()?,
+			None if table.contains_key("merge") => {
+				return Err(config::ConfigError::Message(String::from(
+					"the merge table requires extends",
+				))
+				.into());
+			}
+			None => return Ok(toml::Value::Table(table)),
+		};
+		let strategies = match table.remove("merge") {
+			Some(v) => v.try_into::<HashMap<String, MergeStrategy>>()?,
+			None => HashMap::new(),
+		};
+		let mut resolved = toml::Value::Table(toml::Table::new());
+		for entry in extends {
+			let (key, contents, parent_dir) =
+				if let Some(name) = entry.strip_prefix(BUILTIN_PREFIX) {
+					let contents = BuiltinConfig::get_config(name.to_string())?;
+					(entry.clone(), contents, base_dir.to_path_buf())
+				} else {
+					let path = base_dir.join(&entry);
+					let contents = fs::read_to_string(&path).map_err(|e| {
+						config::ConfigError::Message(format!(
+							"failed to read {path:?} in extends: {e}"
+						))
+					})?;
+					let key = path.canonicalize()?.to_string_lossy().to_string();
+					let parent_dir =
+						path.parent().map(Path::to_path_buf).unwrap_or_default();
+					(key, contents, parent_dir)
+				};
+			if stack.contains(&key) {
+				return Err(config::ConfigError::Message(format!(
+					"circular extends detected: {entry}"
+				))
+				.into());
+			}
+			trace!("Extending the configuration with {entry}");
+			stack.push(key);
+			let format = ConfigFormat::from_path(Path::new(&entry))
+				.unwrap_or(ConfigFormat::Toml);
+			let parent =
+				Self::resolve_value(format.parse(&contents)?, &parent_dir, stack)?;
+			stack.pop();
+			resolved = merge_values(resolved, parent, &strategies, "");
+		}
+		Ok(merge_values(
+			resolved,
+			toml::Value::Table(table),
+			&strategies,
+			"",
+		))
+	}
+}
+
+/// Deep merges the `overlay` value into the `base` value.
+///
+/// Tables are merged key by key, arrays are merged based on the strategy
+/// configured for their dotted `path` (e.g. `git.commit_parsers`) and other
+/// values are replaced.
+fn merge_values(
+	base: toml::Value,
+	overlay: toml::Value,
+	strategies: &HashMap<String, MergeStrategy>,
+	path: &str,
+) -> toml::Value {
+	match (base, overlay) {
+		(toml::Value::Table(mut base), toml::Value::Table(overlay)) => {
+			for (key, value) in overlay {
+				let key_path = if path.is_empty() {
+					key.clone()
+				} else {
+					format!("{path}.{key}")
+				};
+				let value = match base.remove(&key) {
+					Some(base_value) => {
+						merge_values(base_value, value, strategies, &key_path)
+					}
+					None => value,
+				};
+				base.insert(key, value);
+			}
+			toml::Value::Table(base)
+		}
+		(toml::Value::Array(base), toml::Value::Array(overlay)) => {
+			match strategies.get(path).copied().unwrap_or_default() {
+				MergeStrategy::Replace => toml::Value::Array(overlay),
+				MergeStrategy::Prepend => {
+					toml::Value::Array(overlay.into_iter().chain(base).collect())
+				}
+				MergeStrategy::Append => {
+					toml::Value::Array(base.into_iter().chain(overlay).collect())
+				}
+			}
+		}
+		(_, overlay) => overlay,
 	}
 }
 
 #[cfg(test)]
 mod test {
 	use super::*;
+	use crate::embed::EmbeddedConfig;
 	use pretty_assertions::assert_eq;
 	use std::env;
 	#[test]
@@ -464,6 +1169,376 @@ mod test {
 		Ok(())
 	}
 
+	#[test]
+	fn parse_project_config() -> Result<()> {
+		let config = Config::parse_from_str(
+			r##"
+			[changelog]
+			header = "# Changelog"
+			body = "{{ version }}"
+
+			[[project]]
+			name = "core"
+			include_paths = ["crates/core/**"]
+			tag_pattern = "core-v[0-9].*"
+			output = "crates/core/CHANGELOG.md"
+			header = "# Core Changelog"
+
+			[[project]]
+			name = "cli"
+			exclude_paths = ["crates/core/**"]
+			"##,
+		)?;
+		assert_eq!(2, config.projects.len());
+		assert_eq!(
+			Some(vec![String::from("crates/core/**")]),
+			config.projects[0].include_paths
+		);
+		assert_eq!(
+			Some(PathBuf::from("crates/core/CHANGELOG.md")),
+			config.projects[0].output
+		);
+
+		let mut core_config = config.clone();
+		config.projects[0].apply(&mut core_config);
+		assert_eq!(
+			Some("core-v[0-9].*"),
+			core_config.git.tag_pattern.as_ref().map(Regex::as_str)
+		);
+		assert_eq!(
+			Some(String::from("# Core Changelog")),
+			core_config.changelog.header
+		);
+		assert_eq!(
+			Some(String::from("{{ version }}")),
+			core_config.changelog.body
+		);
+
+		let mut cli_config = config.clone();
+		config.projects[1].apply(&mut cli_config);
+		assert_eq!(
+			config.git.tag_pattern.as_ref().map(Regex::as_str),
+			cli_config.git.tag_pattern.as_ref().map(Regex::as_str)
+		);
+		assert_eq!(
+			Some(String::from("# Changelog")),
+			cli_config.changelog.header
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn read_config_from_manifests() -> Result<()> {
+		let toml_config = |prefix: &str| {
+			format!(
+				r#"
+				[{prefix}changelog]
+				header = "Changelog"
+				trim = true
+
+				[{prefix}git]
+				commit_parsers = [{{ message = "^feat", group = "Features" }}]
+				"#
+			)
+		};
+		let json_config = r#"{
+			"changelog": { "header": "Changelog", "trim": true },
+			"git": {
+				"commit_parsers": [{ "message": "^feat", "group": "Features" }]
+			}
+		}"#;
+		for (name, contents) in [
+			(
+				"Cargo.toml",
+				format!(
+					"[package]\nname = \"test\"\n{}",
+					toml_config("package.metadata.git-cliff.")
+				),
+			),
+			("Cargo.toml", toml_config("workspace.metadata.git-cliff.")),
+			("pyproject.toml", toml_config("tool.git-cliff.")),
+			(
+				"package.json",
+				format!(r#"{{ "name": "test", "git-cliff": {json_config} }}"#),
+			),
+			(
+				"composer.json",
+				format!(
+					r#"{{ "name": "test", "extra": {{ "git-cliff": {json_config} }} }}"#
+				),
+			),
+		] {
+			let info = MANIFEST_INFO
+				.iter()
+				.find(|v| v.path == Path::new(name))
+				.expect("manifest info not found");
+			let contents = info.extract(&contents)?.expect("config not found");
+			let config = Config::parse_from_str_strict(&contents)?;
+			assert_eq!(Some(String::from("Changelog")), config.changelog.header);
+			assert_eq!(Some(true), config.changelog.trim);
+			assert_eq!(
+				Some(String::from("Features")),
+				config.git.commit_parsers.unwrap_or_default()[0].group
+			);
+		}
+		assert!(MANIFEST_INFO[2].extract(r#"{ "name": "test" }"#)?.is_none());
+		assert!(MANIFEST_INFO[2]
+			.extract(r#"{ "name": "test", "git-cliff": {}, }"#)?
+			.is_none());
+		assert!(MANIFEST_INFO[0].extract("[package\n")?.is_none());
+		assert!(MANIFEST_INFO[2]
+			.extract(r#"{ "name": "test", "git-cliff": "cliff.toml" }"#)?
+			.is_none());
+		let contents = MANIFEST_INFO[2]
+			.extract(
+				r#"{ "git-cliff": { "changelog": { "header": null, "trim": true } } }"#,
+			)?
+			.expect("config not found");
+		let config = Config::parse_from_str_strict(&contents)?;
+		assert_eq!(None, config.changelog.header);
+		assert_eq!(Some(true), config.changelog.trim);
+		Ok(())
+	}
+
+	#[test]
+	fn parse_config_with_extends() -> Result<()> {
+		let dir = temp_dir::TempDir::new()?;
+		fs::create_dir(dir.path().join("shared"))?;
+		fs::write(
+			dir.path().join("shared").join("cliff.toml"),
+			r#"
+			extends = ["builtin:minimal"]
+
+			[changelog]
+			header = "shared header"
+
+			[git]
+			commit_parsers = [{ message = "^feat", group = "Features" }]
+			filter_commits = true
+			"#,
+		)?;
+		fs::write(
+			dir.path().join("cliff.toml"),
+			r#"
+			extends = ["shared/cliff.toml"]
+
+			[merge]
+			"git.commit_parsers" = "prepend"
+
+			[changelog]
+			trim = false
+
+			[git]
+			commit_parsers = [{ message = "^fix", group = "Bug Fixes" }]
+			"#,
+		)?;
+		let config = Config::parse(&dir.path().join("cliff.toml"))?;
+		let builtin = BuiltinConfig::parse(String::from("minimal"))?.0;
+		assert_eq!(Some(String::from("shared header")), config.changelog.header);
+		assert_eq!(builtin.changelog.body, config.changelog.body);
+		assert_eq!(Some(false), config.changelog.trim);
+		assert_eq!(Some(true), config.git.filter_commits);
+		assert_eq!(
+			vec![
+				Some(String::from("Bug Fixes")),
+				Some(String::from("Features"))
+			],
+			config
+				.git
+				.commit_parsers
+				.unwrap_or_default()
+				.into_iter()
+				.map(|v| v.group)
+				.collect::<Vec<_>>()
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn parse_yaml_and_json_configs() -> Result<()> {
+		let dir = temp_dir::TempDir::new()?;
+		fs::write(
+			dir.path().join("base.json"),
+			r#"{ "git": { "commit_parsers": [{ "message": "^feat", "group": "Features" }] } }"#,
+		)?;
+		fs::write(
+			dir.path().join(".cliff.yaml"),
+			r#"
+extends: base.json
+merge:
+  git.commit_parsers: append
+changelog:
+  header: |
+    # Changelog
+  trim: true
+git:
+  commit_parsers:
+    - message: "^fix"
+      group: Bug Fixes
+"#,
+		)?;
+		let config = Config::parse_strict(&dir.path().join(".cliff.yaml"))?;
+		assert_eq!(Some(String::from("# Changelog\n")), config.changelog.header);
+		assert_eq!(Some(true), config.changelog.trim);
+		assert_eq!(
+			vec![
+				Some(String::from("Features")),
+				Some(String::from("Bug Fixes"))
+			],
+			config
+				.git
+				.commit_parsers
+				.unwrap_or_default()
+				.into_iter()
+				.map(|v| v.group)
+				.collect::<Vec<_>>()
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn parse_null_values() -> Result<()> {
+		let dir = temp_dir::TempDir::new()?;
+		fs::write(
+			dir.path().join("cliff.yaml"),
+			"changelog:\n  header:\n  footer: ~\n  trim: true\ngit:\n  \
+			 commit_preprocessors:\n    - ~\n",
+		)?;
+		fs::write(
+			dir.path().join("cliff.json"),
+			r#"{ "changelog": { "header": null, "trim": true }, "remote": null }"#,
+		)?;
+		for name in ["cliff.yaml", "cliff.json"] {
+			let config = Config::parse_strict(&dir.path().join(name))?;
+			assert_eq!(None, config.changelog.header);
+			assert_eq!(Some(true), config.changelog.trim);
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn convert_config_formats() -> Result<()> {
+		for name in BuiltinConfig::iter() {
+			let contents = BuiltinConfig::get_config(name.to_string())?;
+			let expected: toml::Value = toml::from_str(&contents)?;
+			for format in
+				[ConfigFormat::Toml, ConfigFormat::Yaml, ConfigFormat::Json]
+			{
+				assert_eq!(
+					expected,
+					format.parse(&format.convert(&contents)?)?,
+					"{name} ({format:?})"
+				);
+			}
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn convert_ambiguous_yaml_values() -> Result<()> {
+		let contents = r#"
+[git]
+"on" = "yes"
+values = ["null", "1.0", "0x10", "*alias", "&anchor", "!tag", "~", "a: b"]
+numbers = [1, 1.0, -0.5, inf, -inf]
+flags = [true, false]
+"#;
+		let expected: toml::Value = toml::from_str(contents)?;
+		assert_eq!(
+			expected,
+			ConfigFormat::Yaml.parse(&ConfigFormat::Yaml.convert(contents)?)?
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn merge_config_arrays() {
+		let base: toml::Value =
+			toml::from_str("[git]\ncommit_parsers = [1, 2]\nlink_parsers = [1]")
+				.expect("invalid toml");
+		let overlay: toml::Value =
+			toml::from_str("[git]\ncommit_parsers = [3]\nlink_parsers = [2]")
+				.expect("invalid toml");
+		for (strategy, expected) in [
+			(MergeStrategy::Replace, vec![3]),
+			(MergeStrategy::Prepend, vec![3, 1, 2]),
+			(MergeStrategy::Append, vec![1, 2, 3]),
+		] {
+			let strategies =
+				HashMap::from([(String::from("git.commit_parsers"), strategy)]);
+			let merged =
+				merge_values(base.clone(), overlay.clone(), &strategies, "");
+			assert_eq!(
+				toml::Value::try_from(expected).expect("invalid value"),
+				merged["git"]["commit_parsers"]
+			);
+			assert_eq!(
+				toml::Value::try_from(vec![2]).expect("invalid value"),
+				merged["git"]["link_parsers"]
+			);
+		}
+	}
+
+	#[test]
+	fn detect_circular_extends() -> Result<()> {
+		let dir = temp_dir::TempDir::new()?;
+		fs::write(dir.path().join("a.toml"), r#"extends = ["b.toml"]"#)?;
+		fs::write(dir.path().join("b.toml"), r#"extends = ["a.toml"]"#)?;
+		assert!(Config::parse(&dir.path().join("a.toml")).is_err());
+		Ok(())
+	}
+
+	#[test]
+	fn merge_without_extends() -> Result<()> {
+		let dir = temp_dir::TempDir::new()?;
+		let path = dir.path().join("cliff.toml");
+		fs::write(&path, "[merge]\n\"git.commit_parsers\" = \"prepend\"\n")?;
+		assert!(Config::parse(&path)
+			.is_err_and(|e| e.to_string().contains("requires extends")));
+		Ok(())
+	}
+
+	#[test]
+	fn parse_config_with_unknown_keys() -> Result<()> {
+		let contents = r#"
+		[git]
+		filter_unconventionl = true
+		"#;
+		assert!(Config::parse_from_str(contents).is_ok());
+		assert!(Config::parse_from_str_strict(contents).is_err());
+		assert!(
+			Config::parse_from_str_strict(&EmbeddedConfig::get_config()?).is_ok()
+		);
+		for name in BuiltinConfig::iter() {
+			assert!(
+				Config::parse_from_str_strict(&BuiltinConfig::get_config(
+					name.to_string()
+				)?)
+				.is_ok(),
+				"unknown keys in {name}"
+			);
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn generate_json_schema() -> Result<()> {
+		let schema: serde_json::Value =
+			serde_json::from_str(&Config::json_schema()?)?;
+		for key in ["changelog", "git", "remote", "bump", "extends", "merge"] {
+			assert!(schema["properties"].get(key).is_some(), "{key} is missing");
+		}
+		assert_eq!(
+			serde_json::Value::Bool(false),
+			schema["$defs"]["GitConfig"]["additionalProperties"]
+		);
+		assert_eq!(
+			serde_json::Value::from("string"),
+			schema["$defs"]["TextProcessor"]["properties"]["pattern"]["type"]
+		);
+		Ok(())
+	}
+
 	#[test]
 	fn remote_config() {
 		let remote1 = Remote::new("abc", "xyz1");
@@ -475,4 +1550,31 @@ mod test {
 		assert!(!Remote::new("test", "").is_set());
 		assert!(!Remote::new("", "").is_set());
 	}
+
+	#[test]
+	fn git_config_time_range() -> Result<()> {
+		let mut config = GitConfig {
+			since: Some(String::from("2024-09-01")),
+			until: Some(String::from("2024-09-30")),
+			..Default::default()
+		};
+		assert_eq!((Some(1725148800), Some(1727740799)), config.time_range()?);
+		config.since = Some(String::from("2024-09-01T12:00:00+02:00"));
+		config.until = None;
+		assert_eq!((Some(1725184800), None), config.time_range()?);
+		config.since = Some(String::from("last month"));
+		assert!(config.time_range().is_err());
+		Ok(())
+	}
+
+	#[test]
+	fn release_period_name() -> Result<()> {
+		// 2024-09-09 (Monday of the ISO week 37)
+		assert_eq!("2024-W37", ReleasePeriod::Weekly.name(1725840000)?);
+		assert_eq!("2024-09", ReleasePeriod::Monthly.name(1725840000)?);
+		// 2024-12-30 (Monday of the ISO week 1 of 2025)
+		assert_eq!("2025-W01", ReleasePeriod::Weekly.name(1735516800)?);
+		assert_eq!("2024-12", ReleasePeriod::Monthly.name(1735516800)?);
+		Ok(())
+	}
 }
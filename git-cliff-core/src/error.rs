@@ -49,18 +49,36 @@ pub enum Error {
 	/// Error that may occur while parsing the command line arguments.
 	#[error("Argument error: `{0}`")]
 	ArgumentError(String),
+	/// Error that may occur when the configuration check finds problems.
+	#[error("Configuration check failed with {0} problem(s)")]
+	ConfigCheckError(usize),
 	/// Error that may occur while extracting the embedded content.
 	#[error("Embedded error: `{0}`")]
 	EmbeddedError(String),
 	/// Errors that may occur when deserializing types from TOML format.
 	#[error("Cannot parse TOML: `{0}`")]
 	DeserializeError(#[from] toml::de::Error),
+	/// Errors that may occur when serializing types into TOML format.
+	#[error("Cannot serialize TOML: `{0}`")]
+	SerializeError(#[from] toml::ser::Error),
+	/// Errors that may occur while editing TOML documents.
+	#[error("Cannot edit TOML: `{0}`")]
+	TomlEditError(#[from] toml_edit::TomlError),
 	/// Errors that may occur while de/serializing JSON format.
 	#[error("Cannot de/serialize JSON: `{0}`")]
 	JsonError(#[from] serde_json::Error),
+	/// Errors that may occur while serializing types into YAML format.
+	#[error("Cannot serialize YAML: `{0}`")]
+	YamlError(#[from] yaml_rust2::EmitError),
 	/// Errors that may occur during parsing or compiling a regular expression.
 	#[error("Cannot parse/compile regex: `{0}`")]
 	RegexError(#[from] regex::Error),
+	/// Error that may occur while parsing dates.
+	#[error("Cannot parse the date: `{0}`")]
+	DateParseError(#[from] time::error::Parse),
+	/// Error that may occur when a date is out of range.
+	#[error("Invalid date: `{0}`")]
+	DateRangeError(#[from] time::error::ComponentRange),
 	/// Error that may occur due to system time related anomalies.
 	#[error("System time error: `{0}`")]
 	SystemTimeError(#[from] std::time::SystemTimeError),
@@ -101,6 +119,9 @@ pub enum Error {
 	/// Error that may occur while handling location of directories.
 	#[error("Directory error: `{0}`")]
 	DirsError(String),
+	/// Error that may occur while updating the version files.
+	#[error("Version file error: `{0}`")]
+	VersionFileError(String),
 	/// Error that may occur while constructing patterns.
 	#[error("Pattern error: `{0}`")]
 	PatternError(#[from] glob::PatternError),
@@ -26,6 +26,10 @@ pub mod contributor;
 pub mod embed;
 /// Error handling.
 pub mod error;
+/// Configuration linter.
+pub mod lint;
+/// Configuration migration.
+pub mod migrate;
 /// Common release type.
 pub mod release;
 /// Remote handler.
@@ -39,12 +43,26 @@ pub mod repo;
 pub mod tag;
 /// Template engine.
 pub mod template;
+/// Version files.
+pub mod version;
 
 #[macro_use]
 extern crate log;
 
 /// Default configuration file.
 pub const DEFAULT_CONFIG: &str = "cliff.toml";
+/// Configuration files that are discovered automatically, in order of
+/// precedence.
+pub const CONFIG_FILES: &[&str] = &[
+	DEFAULT_CONFIG,
+	".cliff.toml",
+	"cliff.yaml",
+	"cliff.yml",
+	".cliff.yaml",
+	".cliff.yml",
+	"cliff.json",
+	".cliff.json",
+];
 /// Default output file.
 pub const DEFAULT_OUTPUT: &str = "CHANGELOG.md";
 /// Default ignore file.
@@ -0,0 +1,648 @@
+use crate::commit::{
+	Commit,
+	Link,
+};
+use crate::config::{
+	CommitParser,
+	Config,
+};
+use crate::contributor::RemoteContributor;
+use crate::error::Result;
+use crate::release::{
+	Release,
+	Releases,
+};
+use crate::template::{
+	Template,
+	DEPRECATED_VARIABLES,
+};
+use glob::Pattern;
+use regex::Regex;
+use std::fmt;
+use std::path::Path;
+
+/// Tables that contain regex fields, along with the names of the fields.
+const REGEX_FIELDS: &[(&str, &[&str])] = &[
+	("git", &[
+		"tag_pattern",
+		"skip_tags",
+		"ignore_tags",
+		"count_tags",
+		"release_branch_pattern",
+	]),
+	("bump", &[
+		"custom_major_increment_regex",
+		"custom_minor_increment_regex",
+	]),
+];
+
+/// Arrays of tables that contain regex fields, along with the names of the
+/// fields. An empty table name refers to the top-level arrays.
+const REGEX_ARRAY_FIELDS: &[(&str, &str, &[&str])] = &[
+	("changelog", "postprocessors", &["pattern"]),
+	("git", "commit_preprocessors", &["pattern"]),
+	("git", "commit_parsers", &[
+		"message",
+		"body",
+		"footer",
+		"pattern",
+		"not_message",
+		"not_body",
+		"not_footer",
+		"not_pattern",
+	]),
+	("git", "link_parsers", &["pattern"]),
+	("", "project", &["tag_pattern"]),
+];
+
+/// A problem found in the configuration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Issue {
+	/// Location of the problem, e.g. `git.commit_parsers[1]`.
+	pub location: String,
+	/// Description of the problem.
+	pub message:  String,
+}
+
+impl Issue {
+	/// Constructs a new instance.
+	fn new<S: Into<String>>(location: &str, message: S) -> Self {
+		Self {
+			location: location.to_string(),
+			message:  message.into(),
+		}
+	}
+}
+
+impl fmt::Display for Issue {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}: {}", self.location, self.message)
+	}
+}
+
+/// Checks the given configuration contents and returns the problems found.
+///
+/// Relative paths in `extends` are resolved from `base_dir`.
+pub fn check(contents: &str, base_dir: &Path) -> Result<Vec<Issue>> {
+	let contents = Config::resolve_extends(contents, base_dir)?;
+	let mut value: toml::Value = toml::from_str(&contents)?;
+	let mut issues = check_patterns(&value);
+	let patterns_valid = issues.is_empty();
+	if !patterns_valid {
+		warn!(
+			"Skipping the checks of the commit parsers since some of the patterns \
+			 are invalid."
+		);
+		remove_patterns(&mut value);
+	}
+	let config: Config = value.try_into()?;
+	if patterns_valid {
+		issues.extend(check_commit_parsers(
+			config.git.commit_parsers.as_deref().unwrap_or_default(),
+		));
+	}
+	issues.extend(check_templates(&config)?);
+	Ok(issues)
+}
+
+/// Removes the fields that contain regexes or glob patterns so that the
+/// templates can still be checked when the patterns fail to compile.
+///
+/// The arrays of processors and parsers are removed as a whole while only the
+/// pattern fields of the top-level arrays (i.e. projects) are removed.
+fn remove_patterns(value: &mut toml::Value) {
+	for (table, fields) in REGEX_FIELDS {
+		if let Some(table) = value.get_mut(table).and_then(|v| v.as_table_mut()) {
+			for field in *fields {
+				table.remove(*field);
+			}
+		}
+	}
+	for (table, array, fields) in REGEX_ARRAY_FIELDS {
+		if table.is_empty() {
+			let entries = value.get_mut(array).and_then(|v| v.as_array_mut());
+			for entry in entries.into_iter().flatten() {
+				if let Some(entry) = entry.as_table_mut() {
+					for field in *fields {
+						entry.remove(*field);
+					}
+				}
+			}
+		} else if let Some(table) =
+			value.get_mut(table).and_then(|v| v.as_table_mut())
+		{
+			table.remove(*array);
+		}
+	}
+}
+
+/// Returns the regexes and the glob patterns that fail to compile.
+fn check_patterns(value: &toml::Value) -> Vec<Issue> {
+	let mut issues = Vec::new();
+	let mut check = |location: String, value: Option<&toml::Value>| {
+		if let Some(Err(e)) = value.and_then(|v| v.as_str()).map(Regex::new) {
+			issues.push(Issue::new(&location, format!("invalid regex: {e}")));
+		}
+	};
+	for (table, fields) in REGEX_FIELDS {
+		for field in *fields {
+			check(
+				format!("{table}.{field}"),
+				value.get(table).and_then(|v| v.get(field)),
+			);
+		}
+	}
+	for (table, array, fields) in REGEX_ARRAY_FIELDS {
+		let (entries, prefix) = if table.is_empty() {
+			(value.get(array), String::from(*array))
+		} else {
+			(
+				value.get(table).and_then(|v| v.get(array)),
+				format!("{table}.{array}"),
+			)
+		};
+		let entries = entries.and_then(|v| v.as_array());
+		for (i, entry) in entries.into_iter().flatten().enumerate() {
+			for field in *fields {
+				check(format!("{prefix}[{i}].{field}"), entry.get(field));
+			}
+		}
+	}
+	let parsers = value
+		.get("git")
+		.and_then(|v| v.get("commit_parsers"))
+		.and_then(|v| v.as_array());
+	for (i, parser) in parsers.into_iter().flatten().enumerate() {
+		let paths = parser.get("paths").and_then(|v| v.as_array());
+		for (j, path) in paths.into_iter().flatten().enumerate() {
+			if let Some(Err(e)) = path.as_str().map(Pattern::new) {
+				issues.push(Issue::new(
+					&format!("git.commit_parsers[{i}].paths[{j}]"),
+					format!("invalid glob: {e}"),
+				));
+			}
+		}
+	}
+	issues
+}
+
+/// Returns `true` if the given regex matches every text.
+fn matches_all(regex: &Regex) -> bool {
+	regex.as_str().trim_start_matches('^').trim_end_matches('$') == ".*"
+}
+
+/// Returns `true` if the given parser matches every commit.
+///
+/// This is the case when the parser only has message or body conditions that
+/// match every text. Since all the conditions of a parser must match, any
+/// other condition makes it a regular parser.
+fn is_catch_all(parser: &CommitParser) -> bool {
+	let regexes = [&parser.message, &parser.body];
+	let other_conditions = parser.sha.is_some() ||
+		parser.footer.is_some() ||
+		parser.field.is_some() ||
+		parser.pattern.is_some() ||
+		parser.paths.is_some() ||
+		parser.not_message.is_some() ||
+		parser.not_body.is_some() ||
+		parser.not_footer.is_some() ||
+		parser.not_field.is_some() ||
+		parser.not_pattern.is_some();
+	!other_conditions &&
+		regexes.iter().any(|v| v.is_some()) &&
+		regexes.into_iter().flatten().all(matches_all)
+}
+
+/// Checks the commit parsers for the entries that are shadowed by an earlier
+/// catch-all parser and the entries without a `group` or `skip`.
+fn check_commit_parsers(parsers: &[CommitParser]) -> Vec<Issue> {
+	let mut issues = Vec::new();
+	let mut catch_all = None;
+	for (i, parser) in parsers.iter().enumerate() {
+		let location = format!("git.commit_parsers[{i}]");
+		if let Some(index) = catch_all {
+			issues.push(Issue::new(
+				&location,
+				format!(
+					"parser can never match since it is shadowed by the catch-all \
+					 parser at git.commit_parsers[{index}]"
+				),
+			));
+		} else if is_catch_all(parser) {
+			catch_all = Some(i);
+		}
+		if parser.group.is_none() && parser.skip.is_none() {
+			issues.push(Issue::new(&location, "parser has no `group` or `skip`"));
+		}
+	}
+	issues
+}
+
+/// Returns a release that contains all the fields of the template context.
+fn sample_release() -> Result<Release<'static>> {
+	let mut commit = Commit::new(
+		String::from("a1b2c3d4"),
+		String::from(
+			"feat(config)!: add linter\n\nBody\n\nBREAKING CHANGE: \
+			 Breaking\nCloses: #1",
+		),
+	)
+	.into_conventional()?;
+	commit.links.push(Link {
+		text: String::from("#1"),
+		href: String::from("https://example.com/1"),
+	});
+	commit.remote = Some(RemoteContributor::default());
+	#[allow(unused_mut)]
+	let mut release = Release {
+		version: Some(String::from("v1.0.0")),
+		commits: vec![commit],
+		commit_id: Some(String::from("a1b2c3d4")),
+		previous: Some(Box::default()),
+		..Default::default()
+	};
+	#[cfg(feature = "github")]
+	release
+		.github
+		.contributors
+		.push(RemoteContributor::default());
+	#[cfg(feature = "gitlab")]
+	release
+		.gitlab
+		.contributors
+		.push(RemoteContributor::default());
+	#[cfg(feature = "gitea")]
+	release
+		.gitea
+		.contributors
+		.push(RemoteContributor::default());
+	#[cfg(feature = "bitbucket")]
+	release
+		.bitbucket
+		.contributors
+		.push(RemoteContributor::default());
+	Ok(release)
+}
+
+/// Checks the templates for syntax errors, undefined and deprecated
+/// variables.
+fn check_templates(config: &Config) -> Result<Vec<Issue>> {
+	let mut issues = Vec::new();
+	let release = sample_release()?;
+	let remote = serde_json::to_value(&config.remote)?;
+	let mut release_context = serde_json::to_value(&release)?;
+	release_context["remote"] = remote.clone();
+	let mut releases_context = serde_json::to_value(Releases {
+		releases: &vec![release],
+	})?;
+	releases_context["remote"] = remote;
+	if !config.projects.is_empty() {
+		release_context["project"] = serde_json::Value::from("");
+		releases_context["project"] = serde_json::Value::from("");
+	}
+	let mut templates = vec![(
+		String::from("changelog"),
+		&config.changelog.header,
+		&config.changelog.body,
+		&config.changelog.footer,
+		&config.changelog.tag_message,
+	)];
+	templates.extend(config.projects.iter().enumerate().map(|(i, project)| {
+		(
+			format!("project[{i}]"),
+			&project.header,
+			&project.body,
+			&project.footer,
+			&None,
+		)
+	}));
+	let trim = config.changelog.trim.unwrap_or(true);
+	for (table, header, body, footer, tag_message) in templates {
+		for (name, template, context) in [
+			("header", header, &releases_context),
+			("body", body, &release_context),
+			("footer", footer, &releases_context),
+			("tag_message", tag_message, &release_context),
+		] {
+			let Some(template) = template else {
+				continue;
+			};
+			let location = format!("{table}.{name}");
+			let template = match Template::new(name, template.to_string(), trim) {
+				Ok(v) => v,
+				Err(e) => {
+					issues.push(Issue::new(&location, e.to_string()));
+					continue;
+				}
+			};
+			for variable in template.get_undefined_variables(context)? {
+				issues.push(Issue::new(
+					&location,
+					format!("variable `{variable}` does not exist in the context"),
+				));
+			}
+			for variable in DEPRECATED_VARIABLES {
+				if template.contains_variable(&[variable]) {
+					issues.push(Issue::new(
+						&location,
+						format!(
+							"variable `{variable}` is deprecated, use \
+							 `commit.remote` instead"
+						),
+					));
+				}
+			}
+		}
+	}
+	Ok(issues)
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::embed::{
+		BuiltinConfig,
+		EmbeddedConfig,
+	};
+	use pretty_assertions::assert_eq;
+
+	fn check_str(contents: &str) -> Result<Vec<String>> {
+		Ok(check(contents, Path::new(""))?
+			.into_iter()
+			.map(|v| v.to_string())
+			.collect())
+	}
+
+	#[test]
+	fn check_builtin_configs() -> Result<()> {
+		assert_eq!(
+			Vec::<String>::new(),
+			check_str(&EmbeddedConfig::get_config()?)?
+		);
+		for name in BuiltinConfig::iter() {
+			if cfg!(not(feature = "github")) && name.starts_with("github") {
+				continue;
+			}
+			assert_eq!(
+				Vec::<String>::new(),
+				check_str(&BuiltinConfig::get_config(name.to_string())?)?,
+				"{name}"
+			);
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn check_invalid_regexes() -> Result<()> {
+		let issues = check_str(
+			r#"
+			[git]
+			tag_pattern = "v[0-9"
+			commit_parsers = [
+			  { message = "^feat", group = "Features" },
+			  { message = "^fix(", group = "Bug Fixes" },
+			]
+			"#,
+		)?;
+		assert_eq!(2, issues.len());
+		assert!(issues[0].starts_with("git.tag_pattern: invalid regex"));
+		assert!(
+			issues[1].starts_with("git.commit_parsers[1].message: invalid regex")
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn check_templates_with_invalid_regexes() -> Result<()> {
+		let issues = check_str(
+			r#"
+			[changelog]
+			body = "{{ versoin }}"
+			postprocessors = [{ pattern = "(", replace = "" }]
+
+			[git]
+			commit_parsers = [{ message = "^fix(", group = "Bug Fixes" }]
+
+			[[project]]
+			name = "core"
+			tag_pattern = "core-v[0-9"
+			header = "{{ project }}"
+			"#,
+		)?;
+		assert_eq!(4, issues.len());
+		assert!(issues[0]
+			.starts_with("changelog.postprocessors[0].pattern: invalid regex"));
+		assert!(
+			issues[1].starts_with("git.commit_parsers[0].message: invalid regex")
+		);
+		assert!(issues[2].starts_with("project[0].tag_pattern: invalid regex"));
+		assert_eq!(
+			"changelog.body: variable `versoin` does not exist in the context",
+			issues[3]
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn check_invalid_branch_and_project_regexes() -> Result<()> {
+		let issues = check_str(
+			r#"
+			[git]
+			release_branch_pattern = "release/(.*"
+
+			[[project]]
+			name = "core"
+			tag_pattern = "core-v[0-9"
+			"#,
+		)?;
+		assert_eq!(2, issues.len());
+		assert!(issues[0].starts_with("git.release_branch_pattern: invalid regex"));
+		assert!(issues[1].starts_with("project[0].tag_pattern: invalid regex"));
+		Ok(())
+	}
+
+	#[test]
+	fn check_invalid_globs() -> Result<()> {
+		let issues = check_str(
+			r#"
+			[git]
+			commit_parsers = [
+			  { paths = ["docs/**", "src/[a-z"], group = "Documentation" },
+			]
+			"#,
+		)?;
+		assert_eq!(1, issues.len());
+		assert!(
+			issues[0].starts_with("git.commit_parsers[0].paths[1]: invalid glob")
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn check_parsers() -> Result<()> {
+		let issues = check_str(
+			r#"
+			[changelog]
+			body = ""
+
+			[git]
+			commit_parsers = [
+			  { message = "^feat", group = "Features" },
+			  { message = "^fix", scope = "fix" },
+			  { message = "^.*", not_message = "^chore", group = "Other" },
+			  { message = "^.*", group = "Other" },
+			  { message = "^doc", group = "Documentation" },
+			]
+			"#,
+		)?;
+		assert_eq!(
+			vec![
+				String::from(
+					"git.commit_parsers[1]: parser has no `group` or `skip`"
+				),
+				String::from(
+					"git.commit_parsers[4]: parser can never match since it is \
+					 shadowed by the catch-all parser at git.commit_parsers[3]"
+				),
+			],
+			issues
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn check_parsers_with_conditions() -> Result<()> {
+		let issues = check_str(
+			r#"
+			[changelog]
+			body = ""
+
+			[git]
+			commit_parsers = [
+			  { message = ".*", not_field = "author.name", not_pattern = "bot", group = "Other" },
+			  { message = ".*", field = "author.name", pattern = "bot", skip = true },
+			  { body = ".*", footer = "^Closes", group = "Fixes" },
+			  { message = ".*", paths = ["docs/**"], group = "Documentation" },
+			  { message = "^feat", group = "Features" },
+			  { message = ".*", body = ".*", skip = true },
+			  { message = "^fix", group = "Bug Fixes" },
+			]
+			"#,
+		)?;
+		assert_eq!(
+			vec![String::from(
+				"git.commit_parsers[6]: parser can never match since it is \
+				 shadowed by the catch-all parser at git.commit_parsers[5]"
+			)],
+			issues
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn check_template_variables() -> Result<()> {
+		let issues = check_str(
+			r#"
+			[changelog]
+			header = "{% for release in releases %}{{ release.versoin }}{% endfor %}"
+			body = """
+			{% set title = version | default(value="unreleased") %}{{ title }}
+			{% for group, commits in commits | group_by(attribute="group") %}
+			  {{ group }}
+			  {% for commit in commits %}
+			    {{ commit.message }} {{ commit.scopee }}
+			    {% for link in commit.links %}{{ link.href }}{% endfor %}
+			  {% endfor %}
+			{% endfor %}
+			{{ previous.version }} {{ remote.github.owner }} {{ releas }}
+			"""
+			footer = "{% if releases | length > 0 %}{{ unknown }}{% endif %}"
+			"#,
+		)?;
+		let expected = vec![
+			String::from(
+				"changelog.header: variable `release.versoin` does not exist in \
+				 the context",
+			),
+			String::from(
+				"changelog.body: variable `commit.scopee` does not exist in the \
+				 context",
+			),
+			String::from(
+				"changelog.body: variable `releas` does not exist in the context",
+			),
+			String::from(
+				"changelog.footer: variable `unknown` does not exist in the context",
+			),
+		];
+		assert_eq!(expected, issues);
+		Ok(())
+	}
+
+	#[test]
+	fn check_tag_message_template() -> Result<()> {
+		let issues = check_str(
+			r#"
+			[changelog]
+			body = ""
+			tag_message = "{{ version }}{% for commit in commits %}{{ commit.mesage }}"
+			"#,
+		)?;
+		assert_eq!(1, issues.len());
+		assert!(issues[0].starts_with("changelog.tag_message: "));
+		let issues = check_str(
+			r#"
+			[changelog]
+			body = ""
+			tag_message = "{{ version }} {{ versoin }}"
+			"#,
+		)?;
+		assert_eq!(
+			vec![String::from(
+				"changelog.tag_message: variable `versoin` does not exist in the \
+				 context"
+			)],
+			issues
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn check_project_templates() -> Result<()> {
+		let issues = check_str(
+			r#"
+			[changelog]
+			header = "{{ project }}"
+
+			[[project]]
+			name = "core"
+			body = "{{ version }} {{ versoin }}"
+			"#,
+		)?;
+		assert_eq!(
+			vec![String::from(
+				"project[0].body: variable `versoin` does not exist in the context"
+			)],
+			issues
+		);
+		Ok(())
+	}
+
+	#[test]
+	#[cfg(feature = "github")]
+	fn check_deprecated_variables() -> Result<()> {
+		assert_eq!(
+			vec![String::from(
+				"changelog.body: variable `commit.github` is deprecated, use \
+				 `commit.remote` instead"
+			)],
+			check_str(
+				r#"
+				[changelog]
+				body = "{% for commit in commits %}{{ commit.github.username }}{% endfor %}"
+				"#
+			)?
+		);
+		Ok(())
+	}
+}
@@ -0,0 +1,210 @@
+use crate::error::Result;
+use lazy_regex::{
+	lazy_regex,
+	Lazy,
+	Regex,
+};
+use std::ops::Range;
+use toml_edit::{
+	ImDocument,
+	Item,
+	Table,
+	TableLike,
+};
+
+/// Regex for matching the deprecated template variables.
+static DEPRECATED_VARIABLE_REGEX: Lazy<Regex> =
+	lazy_regex!(r#"\bcommit\.(?:github|gitlab|gitea|bitbucket)\b"#);
+
+/// Regex for matching the deprecated fields in commit parsers.
+static DEPRECATED_FIELD_REGEX: Lazy<Regex> =
+	lazy_regex!(r#"^(["'])(?:github|gitlab|gitea|bitbucket)\b"#);
+
+/// Fields of the changelog and project tables that contain templates.
+const TEMPLATE_FIELDS: &[&str] = &["header", "body", "footer", "tag_message"];
+
+/// Replacement of a part of the configuration contents.
+struct Edit {
+	/// Location of the replaced part.
+	span:        Range<usize>,
+	/// Replacement text.
+	replacement: String,
+}
+
+/// Returns the table-like item at the given path.
+fn get_table<'a>(root: &'a Table, path: &str) -> Option<&'a dyn TableLike> {
+	path.split('.')
+		.try_fold(root as &dyn TableLike, |table, key| {
+			table.get(key).and_then(Item::as_table_like)
+		})
+}
+
+/// Returns the edit that replaces the deprecated parts of the given string
+/// item.
+fn replace_in(
+	contents: &str,
+	item: &Item,
+	regex: &Regex,
+	replacement: &str,
+) -> Option<Edit> {
+	let span = item.as_value().filter(|v| v.is_str())?.span()?;
+	let raw = &contents[span.clone()];
+	let replaced = regex.replace_all(raw, replacement);
+	(replaced != raw).then(|| Edit {
+		span,
+		replacement: replaced.to_string(),
+	})
+}
+
+/// Returns the edits for the deprecated template variables.
+fn migrate_templates(contents: &str, root: &Table) -> Vec<Edit> {
+	let mut tables: Vec<&dyn TableLike> =
+		get_table(root, "changelog").into_iter().collect();
+	if let Some(Item::ArrayOfTables(projects)) = root.get("project") {
+		tables.extend(projects.iter().map(|v| v as &dyn TableLike));
+	}
+	tables
+		.into_iter()
+		.flat_map(|table| {
+			TEMPLATE_FIELDS.iter().filter_map(move |field| {
+				replace_in(
+					contents,
+					table.get(field)?,
+					&DEPRECATED_VARIABLE_REGEX,
+					"commit.remote",
+				)
+			})
+		})
+		.collect()
+}
+
+/// Returns the edits for the deprecated fields in commit parsers.
+fn migrate_commit_parsers(contents: &str, root: &Table) -> Vec<Edit> {
+	let Some(parsers) = get_table(root, "git").and_then(|v| v.get("commit_parsers"))
+	else {
+		return Vec::new();
+	};
+	let parsers: Vec<&dyn TableLike> = match parsers {
+		Item::ArrayOfTables(array) => {
+			array.iter().map(|v| v as &dyn TableLike).collect()
+		}
+		Item::Value(value) => value
+			.as_array()
+			.into_iter()
+			.flatten()
+			.filter_map(|v| v.as_inline_table())
+			.map(|v| v as &dyn TableLike)
+			.collect(),
+		_ => Vec::new(),
+	};
+	parsers
+		.into_iter()
+		.filter_map(|parser| {
+			replace_in(
+				contents,
+				parser.get("field")?,
+				&DEPRECATED_FIELD_REGEX,
+				"${1}remote",
+			)
+		})
+		.collect()
+}
+
+/// Migrates the deprecated template variables and commit parser fields in the
+/// given TOML configuration.
+///
+/// Only the changed parts are rewritten so that the comments and formatting
+/// are preserved.
+pub fn migrate(contents: &str) -> Result<String> {
+	let document = ImDocument::parse(contents)?;
+	let root = document.as_table();
+	let mut edits = migrate_templates(contents, root);
+	edits.extend(migrate_commit_parsers(contents, root));
+	edits.sort_by_key(|edit| edit.span.start);
+	let mut migrated = contents.to_string();
+	for edit in edits.into_iter().rev() {
+		migrated.replace_range(edit.span, &edit.replacement);
+	}
+	Ok(migrated)
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn migrate_template_variables() -> Result<()> {
+		let contents = r#"
+# changelog settings
+[changelog]
+header = "{{ commit.github }}"
+body = """
+{% for commit in commits %}
+    {{ commit.message }} by {{ commit.gitlab.username }} # keep
+    {% if commit.bitbucket.pr_number %}(#{{ commit.bitbucket.pr_number }}){% endif %}
+{% endfor %}
+{{ release.github.contributors | length }}
+"""
+"#;
+		let expected = r#"
+# changelog settings
+[changelog]
+header = "{{ commit.remote }}"
+body = """
+{% for commit in commits %}
+    {{ commit.message }} by {{ commit.remote.username }} # keep
+    {% if commit.remote.pr_number %}(#{{ commit.remote.pr_number }}){% endif %}
+{% endfor %}
+{{ release.github.contributors | length }}
+"""
+"#;
+		assert_eq!(expected, migrate(contents)?);
+		assert_eq!(
+			"[changelog]\ntag_message = \"{{ commit.remote }}\"\n",
+			migrate("[changelog]\ntag_message = \"{{ commit.github }}\"\n")?
+		);
+		assert_eq!(
+			"[[project]]\nname = \"core\"\nbody = \"{{ commit.remote }}\"\n",
+			migrate(
+				"[[project]]\nname = \"core\"\nbody = \"{{ commit.gitea }}\"\n"
+			)?
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn migrate_commit_parser_fields() -> Result<()> {
+		let contents = r#"
+[git]
+commit_parsers = [
+  { field = "github.username", pattern = "bot", skip = true }, # bots
+  { field = 'gitea.pr_labels', pattern = "docs", group = "Docs" },
+  { field = "author.name", pattern = "me", group = "Mine" },
+]
+"#;
+		let expected = r#"
+[git]
+commit_parsers = [
+  { field = "remote.username", pattern = "bot", skip = true }, # bots
+  { field = 'remote.pr_labels', pattern = "docs", group = "Docs" },
+  { field = "author.name", pattern = "me", group = "Mine" },
+]
+"#;
+		assert_eq!(expected, migrate(contents)?);
+		assert_eq!(
+			"[[git.commit_parsers]]\nfield = \"remote.pr_title\"\nskip = true\n",
+			migrate(
+				"[[git.commit_parsers]]\nfield = \"gitlab.pr_title\"\nskip = true\n"
+			)?
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn migrate_up_to_date_config() -> Result<()> {
+		let contents = crate::embed::EmbeddedConfig::get_config()?;
+		assert_eq!(contents, migrate(&contents)?);
+		Ok(())
+	}
+}
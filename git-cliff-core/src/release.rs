@@ -2,6 +2,7 @@ use crate::commit::commits_to_conventional_commits;
 use crate::error::Result;
 use crate::{
 	commit::Commit,
+	commit::DiffStats,
 	config::Bump,
 	config::BumpType,
 };
@@ -25,41 +26,64 @@ use serde::{
 	Serialize,
 };
 use serde_json::value::Value;
+use std::collections::HashSet;
+
+/// Breaking change introduced in a release.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BreakingChange {
+	/// Commit ID.
+	pub id:          String,
+	/// Group of the commit.
+	pub group:       Option<String>,
+	/// Scope of the commit.
+	pub scope:       Option<String>,
+	/// Description of the breaking change.
+	pub description: String,
+}
 
 /// Representation of a release.
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all(serialize = "camelCase"))]
 pub struct Release<'a> {
 	/// Release version, git tag.
-	pub version:    Option<String>,
+	pub version:          Option<String>,
 	/// git tag's message.
-	pub message:    Option<String>,
+	pub message:          Option<String>,
 	/// Commits made for the release.
 	#[serde(deserialize_with = "commits_to_conventional_commits")]
-	pub commits:    Vec<Commit<'a>>,
+	pub commits:          Vec<Commit<'a>>,
 	/// Commit ID of the tag.
 	#[serde(rename = "commit_id")]
-	pub commit_id:  Option<String>,
+	pub commit_id:        Option<String>,
 	/// Timestamp of the release in seconds, from epoch.
-	pub timestamp:  i64,
+	pub timestamp:        i64,
 	/// Previous release.
-	pub previous:   Option<Box<Release<'a>>>,
+	pub previous:         Option<Box<Release<'a>>>,
 	/// Repository path.
-	pub repository: Option<String>,
+	pub repository:       Option<String>,
+	/// Statistics of the changes made in the release.
+	#[serde(default)]
+	pub stats:            DiffStats,
+	/// Breaking changes of the commits.
+	#[serde(default, rename = "breaking_changes")]
+	pub breaking_changes: Vec<BreakingChange>,
+	/// Pre-releases that are folded into this release.
+	#[serde(default)]
+	pub prereleases:      Vec<Release<'a>>,
 	/// Arbitrary data to be used with the `--from-context` CLI option.
-	pub extra:      Option<Value>,
+	pub extra:            Option<Value>,
 	/// Contributors.
 	#[cfg(feature = "github")]
-	pub github:     RemoteReleaseMetadata,
+	pub github:           RemoteReleaseMetadata,
 	/// Contributors.
 	#[cfg(feature = "gitlab")]
-	pub gitlab:     RemoteReleaseMetadata,
+	pub gitlab:           RemoteReleaseMetadata,
 	/// Contributors.
 	#[cfg(feature = "gitea")]
-	pub gitea:      RemoteReleaseMetadata,
+	pub gitea:            RemoteReleaseMetadata,
 	/// Contributors.
 	#[cfg(feature = "bitbucket")]
-	pub bitbucket:  RemoteReleaseMetadata,
+	pub bitbucket:        RemoteReleaseMetadata,
 }
 
 #[cfg(feature = "github")]
@@ -75,6 +99,44 @@ crate::update_release_metadata!(gitea, update_gitea_metadata);
 crate::update_release_metadata!(bitbucket, update_bitbucket_metadata);
 
 impl Release<'_> {
+	/// Calculates the statistics of the changes made by the commits.
+	///
+	/// Commits with the same ID (e.g. split commits) are counted once and the
+	/// changes of the folded fixup commits are included.
+	pub fn calculate_stats(&self) -> DiffStats {
+		let mut ids = HashSet::new();
+		DiffStats::from_files(
+			self.commits
+				.iter()
+				.filter(|commit| ids.insert(&commit.id))
+				.flat_map(|commit| {
+					commit
+						.files
+						.iter()
+						.chain(commit.fixups.iter().flat_map(|fixup| &fixup.files))
+				}),
+		)
+	}
+
+	/// Collects the breaking changes of the commits.
+	///
+	/// Commits with the same ID (e.g. split commits) are included once.
+	pub fn collect_breaking_changes(&self) -> Vec<BreakingChange> {
+		let mut ids = HashSet::new();
+		self.commits
+			.iter()
+			.filter_map(|commit| {
+				let description = commit.breaking_description()?;
+				ids.insert(&commit.id).then(|| BreakingChange {
+					id:          commit.id.clone(),
+					group:       commit.resolved_group().map(String::from),
+					scope:       commit.resolved_scope().map(String::from),
+					description: description.to_string(),
+				})
+			})
+			.collect()
+	}
+
 	/// Calculates the next version based on the commits.
 	///
 	/// It uses the default bump version configuration to calculate the next
@@ -97,26 +159,10 @@ impl Release<'_> {
 			.and_then(|release| release.version.clone())
 		{
 			Some(version) => {
-				let mut semver = Version::parse(&version);
-				let mut prefix = None;
-				if semver.is_err() && version.split('.').count() >= 2 {
-					let mut found_numeric = false;
-					for (i, c) in version.chars().enumerate() {
-						if c.is_numeric() && !found_numeric {
-							found_numeric = true;
-							let version_prefix = version[..i].to_string();
-							let remaining = version[i..].to_string();
-							let version = Version::parse(&remaining);
-							if version.is_ok() {
-								semver = version;
-								prefix = Some(version_prefix);
-								break;
-							}
-						} else if !c.is_numeric() && found_numeric {
-							found_numeric = false;
-						}
-					}
-				}
+				let semver = parse_version(&version);
+				let prefix =
+					semver.as_ref().ok().and_then(|(prefix, _)| prefix.clone());
+				let semver = semver.map(|(_, version)| version);
 				let mut next_version = VersionUpdater::new()
 					.with_features_always_increment_minor(
 						config.features_always_bump_minor.unwrap_or(true),
@@ -166,6 +212,89 @@ impl Release<'_> {
 	}
 }
 
+/// Parses the semantic version of the given version string.
+///
+/// If the version has a prefix (e.g. `v` in `v1.0.0` or `my-app-` in
+/// `my-app-1.0.0`), it is stripped and returned along with the version.
+pub fn parse_version(
+	version: &str,
+) -> std::result::Result<(Option<String>, Version), semver::Error> {
+	let mut semver = Version::parse(version).map(|v| (None, v));
+	if semver.is_err() && version.split('.').count() >= 2 {
+		let mut found_numeric = false;
+		for (i, c) in version.char_indices() {
+			if c.is_numeric() && !found_numeric {
+				found_numeric = true;
+				let version_prefix = version[..i].to_string();
+				let remaining = version[i..].to_string();
+				let version = Version::parse(&remaining);
+				if let Ok(version) = version {
+					semver = Ok((Some(version_prefix), version));
+					break;
+				}
+			} else if !c.is_numeric() && found_numeric {
+				found_numeric = false;
+			}
+		}
+	}
+	semver
+}
+
+/// Folds the pre-releases into their final releases.
+///
+/// The releases are expected to be in chronological order. The commits of the
+/// pre-releases (e.g. `v2.0.0-rc.1`) are prepended to the commits of the final
+/// release with the same version (e.g. `v2.0.0`) and the pre-releases are kept
+/// in [`Release::prereleases`]. Pre-releases without a final release are kept
+/// as they are.
+pub fn fold_prereleases(releases: Vec<Release<'_>>) -> Vec<Release<'_>> {
+	let version_of = |release: &Release| {
+		release
+			.version
+			.as_deref()
+			.and_then(|version| parse_version(version).ok())
+			.map(|(_, version)| version)
+	};
+	let mut folded = Vec::new();
+	let mut prereleases = Vec::new();
+	for mut release in releases {
+		match version_of(&release) {
+			Some(version) if !version.pre.is_empty() => {
+				prereleases.push(release);
+				continue;
+			}
+			Some(version) => {
+				let (matching, others): (Vec<_>, Vec<_>) =
+					prereleases.drain(..).partition(|prerelease| {
+						version_of(prerelease).is_some_and(|v| {
+							(v.major, v.minor, v.patch) ==
+								(version.major, version.minor, version.patch)
+						})
+					});
+				folded.extend(others);
+				if let Some(first_prerelease) = matching.first() {
+					release.previous.clone_from(&first_prerelease.previous);
+					release.commits = matching
+						.iter()
+						.flat_map(|prerelease| prerelease.commits.clone())
+						.chain(release.commits)
+						.collect();
+					release.prereleases = matching;
+				}
+			}
+			None => folded.append(&mut prereleases),
+		}
+		folded.push(release);
+	}
+	folded.append(&mut prereleases);
+	for i in 1..folded.len() {
+		let mut previous = folded[i - 1].clone();
+		previous.previous = None;
+		folded[i].previous = Some(Box::new(previous));
+	}
+	folded
+}
+
 /// Representation of a list of releases.
 #[derive(Serialize)]
 pub struct Releases<'a> {
@@ -190,6 +319,9 @@ mod test {
 			Release {
 				version: None,
 				message: None,
+				stats: DiffStats::default(),
+				breaking_changes: vec![],
+				prereleases: vec![],
 				extra: None,
 				commits: commits
 					.iter()
@@ -295,6 +427,7 @@ mod test {
 					custom_major_increment_regex: None,
 					custom_minor_increment_regex: None,
 					bump_type:                    None,
+					version_files:                None,
 				})?;
 			assert_eq!(expected_version, &next_version);
 		}
@@ -319,6 +452,7 @@ mod test {
 					custom_major_increment_regex: None,
 					custom_minor_increment_regex: None,
 					bump_type:                    None,
+					version_files:                None,
 				})?;
 			assert_eq!(expected_version, &next_version);
 		}
@@ -343,6 +477,7 @@ mod test {
 					custom_major_increment_regex: None,
 					custom_minor_increment_regex: None,
 					bump_type:                    None,
+					version_files:                None,
 				})?;
 			assert_eq!(expected_version, &next_version);
 		}
@@ -367,12 +502,85 @@ mod test {
 					custom_major_increment_regex: None,
 					custom_minor_increment_regex: None,
 					bump_type:                    None,
+					version_files:                None,
 				})?
 			);
 		}
 		Ok(())
 	}
 
+	#[cfg(feature = "github")]
+	#[test]
+	fn update_github_metadata_with_coauthors() -> Result<()> {
+		use crate::commit::Signature;
+		use crate::remote::github::{
+			GitHubCommit,
+			GitHubCommitAuthor,
+		};
+		let commit = |id: &str, email: &str, message: &str| Commit {
+			id: id.to_string(),
+			message: message.to_string(),
+			author: Signature {
+				email: Some(email.to_string()),
+				..Default::default()
+			},
+			..Default::default()
+		};
+		let mut release = Release {
+			commits: vec![
+				commit("1", "orhun@example.com", "add the parser"),
+				commit(
+					"2",
+					"someone@example.com",
+					"pair on the parser\n\nCo-authored-by: Orhun \
+					 <orhun@example.com>\nCo-authored-by: Jane \
+					 <12345+jane@users.noreply.github.com>\nCo-authored-by: John \
+					 <john@example.com>",
+				),
+			],
+			..Default::default()
+		};
+		let remote_commit = |id: &str, login: &str| -> Box<dyn RemoteCommit> {
+			Box::new(GitHubCommit {
+				sha:    id.to_string(),
+				author: Some(GitHubCommitAuthor {
+					login: Some(login.to_string()),
+				}),
+				commit: None,
+			})
+		};
+		release.update_github_metadata(
+			vec![remote_commit("1", "orhun"), remote_commit("2", "someone")],
+			vec![],
+		)?;
+		assert_eq!(
+			vec![
+				Some(String::from("orhun")),
+				Some(String::from("someone")),
+				Some(String::from("jane")),
+			],
+			release
+				.github
+				.contributors
+				.iter()
+				.map(|v| v.username.clone())
+				.collect::<Vec<_>>()
+		);
+		assert_eq!(
+			vec![
+				Some(String::from("Orhun")),
+				Some(String::from("Jane")),
+				Some(String::from("John")),
+			],
+			release.commits[1]
+				.coauthors()
+				.into_iter()
+				.map(|v| v.name)
+				.collect::<Vec<_>>()
+		);
+		Ok(())
+	}
+
 	#[cfg(feature = "github")]
 	#[test]
 	fn update_github_metadata() -> Result<()> {
@@ -388,6 +596,9 @@ mod test {
 		let mut release = Release {
 			version: None,
 			message: None,
+			stats: DiffStats::default(),
+			breaking_changes: vec![],
+			prereleases: vec![],
 			extra: None,
 			commits: vec![
 				Commit::from(String::from(
@@ -756,6 +967,9 @@ mod test {
 		let mut release = Release {
 			version: None,
 			message: None,
+			stats: DiffStats::default(),
+			breaking_changes: vec![],
+			prereleases: vec![],
 			extra: None,
 			commits: vec![
 				Commit::from(String::from(
@@ -1146,6 +1360,9 @@ mod test {
 		let mut release = Release {
 			version: None,
 			message: None,
+			stats: DiffStats::default(),
+			breaking_changes: vec![],
+			prereleases: vec![],
 			extra: None,
 			commits: vec![
 				Commit::from(String::from(
@@ -1504,6 +1721,9 @@ mod test {
 		let mut release = Release {
 			version: None,
 			message: None,
+			stats: DiffStats::default(),
+			breaking_changes: vec![],
+			prereleases: vec![],
 			extra: None,
 			commits: vec![
 				Commit::from(String::from(
@@ -1785,4 +2005,173 @@ mod test {
 
 		Ok(())
 	}
+
+	#[test]
+	fn release_stats() {
+		use crate::commit::{
+			FileChange,
+			FileChangeKind,
+		};
+		let file = |path: &str, insertions, deletions| FileChange {
+			path: path.into(),
+			old_path: None,
+			kind: FileChangeKind::Modified,
+			insertions,
+			deletions,
+		};
+		let commit = |id: &str, files| Commit {
+			id: id.to_string(),
+			files,
+			..Default::default()
+		};
+		let release = Release {
+			commits: vec![
+				commit("1", vec![
+					file("README.md", 3, 1),
+					file("src/lib.rs", 10, 0),
+				]),
+				commit("1", vec![
+					file("README.md", 3, 1),
+					file("src/lib.rs", 10, 0),
+				]),
+				commit("2", vec![file("src/lib.rs", 2, 5)]),
+				commit("3", vec![]),
+			],
+			..Default::default()
+		};
+		assert_eq!(
+			DiffStats {
+				files:      2,
+				insertions: 15,
+				deletions:  6,
+			},
+			release.calculate_stats()
+		);
+		assert_eq!(
+			DiffStats {
+				files:      2,
+				insertions: 13,
+				deletions:  1,
+			},
+			DiffStats::from_files(&release.commits[0].files)
+		);
+	}
+
+	#[test]
+	fn release_breaking_changes() -> Result<()> {
+		let commit = |id: &str, message: &str| {
+			Commit::new(id.to_string(), message.to_string()).into_conventional()
+		};
+		let mut breaking_commit =
+			commit("1", "feat(api)!: remove the v1 endpoints")?;
+		breaking_commit.group = Some(String::from("Features"));
+		let release = Release {
+			commits: vec![
+				breaking_commit.clone(),
+				breaking_commit,
+				commit("2", "fix: handle the errors")?,
+				commit(
+					"3",
+					"refactor: rename the config\n\nBREAKING CHANGE: `foo` is \
+					 renamed to `bar`",
+				)?,
+			],
+			..Default::default()
+		};
+		assert_eq!(
+			vec![
+				BreakingChange {
+					id:          String::from("1"),
+					group:       Some(String::from("Features")),
+					scope:       Some(String::from("api")),
+					description: String::from("remove the v1 endpoints"),
+				},
+				BreakingChange {
+					id:          String::from("3"),
+					group:       Some(String::from("refactor")),
+					scope:       None,
+					description: String::from("`foo` is renamed to `bar`"),
+				},
+			],
+			release.collect_breaking_changes()
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn parse_prefixed_version() {
+		for (version, expected) in [
+			("1.0.0", Some((None, "1.0.0"))),
+			("v2.1.0-rc.1", Some((Some("v"), "2.1.0-rc.1"))),
+			("my-app-v1.2.3", Some((Some("my-app-v"), "1.2.3"))),
+			("1.0-beta", None),
+			("nightly", None),
+		] {
+			assert_eq!(
+				expected.map(|(prefix, version)| (
+					prefix.map(String::from),
+					Version::parse(version).expect("invalid version")
+				)),
+				parse_version(version).ok()
+			);
+		}
+	}
+
+	#[test]
+	fn fold_prereleases_into_final_release() {
+		let release = |version: Option<&str>, commits: &[&str]| Release {
+			version: version.map(String::from),
+			commits: commits
+				.iter()
+				.map(|id| Commit::new(id.to_string(), format!("feat: {id}")))
+				.collect(),
+			..Default::default()
+		};
+		let releases = fold_prereleases(vec![
+			release(Some("v1.0.0"), &["1"]),
+			release(Some("v2.0.0-rc.1"), &["2", "3"]),
+			release(Some("v2.0.0-rc.2"), &["4"]),
+			release(Some("v2.0.0"), &["5"]),
+			release(Some("v3.0.0-beta.1"), &["6"]),
+			release(None, &["7"]),
+		]);
+		let ids = |release: &Release| {
+			release
+				.commits
+				.iter()
+				.map(|commit| commit.id.clone())
+				.collect::<Vec<_>>()
+		};
+		assert_eq!(
+			vec![
+				Some(String::from("v1.0.0")),
+				Some(String::from("v2.0.0")),
+				Some(String::from("v3.0.0-beta.1")),
+				None
+			],
+			releases
+				.iter()
+				.map(|release| release.version.clone())
+				.collect::<Vec<_>>()
+		);
+		assert_eq!(vec!["2", "3", "4", "5"], ids(&releases[1]));
+		assert_eq!(
+			vec![vec!["2", "3"], vec!["4"]],
+			releases[1].prereleases.iter().map(ids).collect::<Vec<_>>()
+		);
+		assert_eq!(
+			Some(String::from("v1.0.0")),
+			releases[1]
+				.previous
+				.as_ref()
+				.and_then(|release| release.version.clone())
+		);
+		assert_eq!(
+			Some(String::from("v2.0.0")),
+			releases[2]
+				.previous
+				.as_ref()
+				.and_then(|release| release.version.clone())
+		);
+	}
 }
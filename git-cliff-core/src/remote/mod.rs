@@ -33,6 +33,11 @@ use http_cache_reqwest::{
 	HttpCache,
 	HttpCacheOptions,
 };
+use lazy_regex::{
+	lazy_regex,
+	Lazy,
+	Regex,
+};
 use reqwest::header::{
 	HeaderMap,
 	HeaderValue,
@@ -124,6 +129,19 @@ pub struct RemoteReleaseMetadata {
 	pub contributors: Vec<RemoteContributor>,
 }
 
+/// Regex for matching the no-reply email addresses of GitHub and GitLab,
+/// e.g. `12345+username@users.noreply.github.com`.
+static NOREPLY_EMAIL_REGEX: Lazy<Regex> =
+	lazy_regex!(r"^(?:\d+[+-])?([^@+]+)@users\.noreply\.(?:github|gitlab)\.com$");
+
+/// Returns the username from the given no-reply email address.
+pub(crate) fn username_from_email(email: &str) -> Option<String> {
+	NOREPLY_EMAIL_REGEX
+		.captures(email)
+		.and_then(|captures| captures.get(1))
+		.map(|v| v.as_str().to_string())
+}
+
 /// Creates a HTTP client for the remote.
 fn create_remote_client(
 	remote: &Remote,
@@ -367,6 +385,39 @@ macro_rules! update_release_metadata {
 						true
 					}
 				});
+				// add the co-authors whose usernames are known to the contributors,
+				// the others are omitted since the contributors are identified by
+				// their usernames
+				let usernames = self
+					.commits
+					.iter()
+					.filter_map(|commit| {
+						Some((
+							commit.author.email.clone()?,
+							commit.$remote.username.clone()?,
+						))
+					})
+					.collect::<std::collections::HashMap<String, String>>();
+				for commit in self.commits.iter() {
+					for coauthor in commit.coauthors() {
+						let username = coauthor.email.as_deref().and_then(|email| {
+							usernames.get(email).cloned().or_else(|| {
+								$crate::remote::username_from_email(email)
+							})
+						});
+						if username.is_some() &&
+							!contributors.iter().any(|v| v.username == username)
+						{
+							contributors.push(RemoteContributor {
+								username,
+								pr_title: commit.$remote.pr_title.clone(),
+								pr_number: commit.$remote.pr_number,
+								pr_labels: commit.$remote.pr_labels.clone(),
+								is_first_time: false,
+							});
+						}
+					}
+				}
 				// mark contributors as first-time
 				self.$remote.contributors = contributors
 					.into_iter()
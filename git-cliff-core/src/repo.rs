@@ -1,17 +1,31 @@
-use crate::config::Remote;
+use crate::commit::{
+	FileChange,
+	FileChangeKind,
+	Signature,
+};
+use crate::config::{
+	DateField,
+	Remote,
+	TagSort,
+};
 use crate::error::{
 	Error,
 	Result,
 };
+use crate::release::parse_version;
 use crate::tag::Tag;
 use git2::{
 	BranchType,
 	Commit,
+	Delta,
 	DescribeOptions,
+	DiffFindOptions,
+	Mailmap,
+	ObjectType,
 	Oid,
+	Patch,
 	Repository as GitRepository,
 	Sort,
-	TreeWalkMode,
 };
 use glob::Pattern;
 use indexmap::IndexMap;
@@ -20,8 +34,21 @@ use lazy_regex::{
 	Lazy,
 	Regex,
 };
-use std::io;
-use std::path::PathBuf;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fs;
+use std::io::{
+	self,
+	Write,
+};
+use std::path::{
+	Path,
+	PathBuf,
+};
+use std::process::{
+	Command,
+	Stdio,
+};
 use url::Url;
 
 /// Regex for replacing the signature part of a tag message.
@@ -33,6 +60,10 @@ static TAG_SIGNATURE_REGEX: Lazy<Regex> = lazy_regex!(
 /// Name of the cache file for changed files.
 const CHANGED_FILES_CACHE: &str = "changed_files_cache";
 
+/// Key of the cached revision walks, the range, the first-parent mode and the
+/// lower bound of the commit time.
+type RevwalkKey = (Option<String>, bool, Option<i64>);
+
 /// Wrapper for [`Repository`] type from git2.
 ///
 /// [`Repository`]: GitRepository
@@ -42,6 +73,10 @@ pub struct Repository {
 	path:                     PathBuf,
 	/// Cache path for the changed files of the commits.
 	changed_files_cache_path: PathBuf,
+	/// Commit IDs of the revision walks, mapped to their keys.
+	revwalk_cache:            RefCell<HashMap<RevwalkKey, Vec<Oid>>>,
+	/// Mailmaps for resolving the identities, in the order of precedence.
+	mailmaps:                 Vec<Mailmap>,
 }
 
 impl Repository {
@@ -61,10 +96,19 @@ impl Repository {
 				.path()
 				.join(env!("CARGO_PKG_NAME"))
 				.join(CHANGED_FILES_CACHE);
+			let mailmaps = match inner.mailmap() {
+				Ok(mailmap) => vec![mailmap],
+				Err(e) => {
+					warn!("Failed to read the mailmap of {path:?}: {e}");
+					Vec::new()
+				}
+			};
 			Ok(Self {
 				inner,
 				path,
 				changed_files_cache_path,
+				revwalk_cache: RefCell::new(HashMap::new()),
+				mailmaps,
 			})
 		} else {
 			Err(Error::IoError(io::Error::new(
@@ -74,6 +118,43 @@ impl Repository {
 		}
 	}
 
+	/// Adds the mailmap file at the given path.
+	///
+	/// Relative paths are resolved from the root of the repository. Its
+	/// entries take precedence over the repository's `.mailmap`.
+	pub fn add_mailmap(&mut self, path: &Path) -> Result<()> {
+		let path = self.path().join(path);
+		let mailmap = Mailmap::from_buffer(&fs::read_to_string(path)?)?;
+		self.mailmaps.insert(0, mailmap);
+		Ok(())
+	}
+
+	/// Returns the author and committer of the commit with their canonical
+	/// identities from the mailmaps.
+	pub fn commit_signatures(&self, commit: &Commit) -> (Signature, Signature) {
+		let resolve = |signature: git2::Signature<'_>| {
+			self.mailmaps
+				.iter()
+				.find_map(|mailmap| {
+					let resolved = mailmap.resolve_signature(&signature).ok()?;
+					(resolved.name_bytes() != signature.name_bytes() ||
+						resolved.email_bytes() != signature.email_bytes())
+					.then_some(resolved)
+				})
+				.map(Signature::from)
+				.unwrap_or_else(|| Signature::from(signature))
+		};
+		(resolve(commit.author()), resolve(commit.committer()))
+	}
+
+	/// Returns the timestamp of the commit for the given date field.
+	pub fn commit_time(&self, commit: &Commit, date_field: DateField) -> i64 {
+		match date_field {
+			DateField::Committer => commit.time().seconds(),
+			DateField::Author => commit.author().when().seconds(),
+		}
+	}
+
 	/// Returns the path of the repository.
 	pub fn path(&self) -> PathBuf {
 		let mut path = self.inner.path().to_path_buf();
@@ -85,15 +166,79 @@ impl Repository {
 
 	/// Parses and returns the commits.
 	///
-	/// Sorts the commits by their time.
+	/// Sorts the commits by their time. If `first_parent` is set, only the
+	/// first parent of the merge commits is followed.
+	///
+	/// If `since` is set, the walk stops at the commits that are committed
+	/// before the given timestamp (along with their ancestors). The exact
+	/// time range of the commits is still expected to be checked by the
+	/// caller.
 	pub fn commits(
 		&self,
 		range: Option<&str>,
 		include_path: Option<Vec<Pattern>>,
 		exclude_path: Option<Vec<Pattern>>,
+		first_parent: bool,
+		since: Option<i64>,
 	) -> Result<Vec<Commit>> {
+		let commits: Vec<Commit> = self
+			.revwalk(range, first_parent, since)?
+			.into_iter()
+			.filter_map(|id| self.inner.find_commit(id).ok())
+			.collect();
+		Ok(self.filter_commits_by_path(&commits, include_path, exclude_path))
+	}
+
+	/// Returns the commits that change the files matching the given include
+	/// and exclude patterns.
+	pub fn filter_commits_by_path<'c>(
+		&self,
+		commits: &[Commit<'c>],
+		include_path: Option<Vec<Pattern>>,
+		exclude_path: Option<Vec<Pattern>>,
+	) -> Vec<Commit<'c>> {
+		if include_path.is_none() && exclude_path.is_none() {
+			return commits.to_vec();
+		}
+		let include_patterns = include_path.map(|patterns| {
+			patterns.into_iter().map(Self::normalize_pattern).collect()
+		});
+		let exclude_patterns = exclude_path.map(|patterns| {
+			patterns.into_iter().map(Self::normalize_pattern).collect()
+		});
+		commits
+			.iter()
+			.filter(|commit| {
+				self.should_retain_commit(
+					commit,
+					&include_patterns,
+					&exclude_patterns,
+				)
+			})
+			.cloned()
+			.collect()
+	}
+
+	/// Returns the commit IDs in the given range.
+	///
+	/// The result is cached so that the history is walked only once when the
+	/// commits of the same range are requested multiple times (e.g. for
+	/// multiple projects).
+	fn revwalk(
+		&self,
+		range: Option<&str>,
+		first_parent: bool,
+		since: Option<i64>,
+	) -> Result<Vec<Oid>> {
+		let key = (range.map(String::from), first_parent, since);
+		if let Some(ids) = self.revwalk_cache.borrow().get(&key) {
+			return Ok(ids.clone());
+		}
 		let mut revwalk = self.inner.revwalk()?;
 		revwalk.set_sorting(Sort::TOPOLOGICAL)?;
+		if first_parent {
+			revwalk.simplify_first_parent()?;
+		}
 		if let Some(range) = range {
 			if range.contains("..") {
 				revwalk.push_range(range)?;
@@ -104,26 +249,66 @@ impl Repository {
 		} else {
 			revwalk.push_head()?;
 		}
-		let mut commits: Vec<Commit> = revwalk
-			.filter_map(|id| id.ok())
-			.filter_map(|id| self.inner.find_commit(id).ok())
-			.collect();
-		if include_path.is_some() || exclude_path.is_some() {
-			let include_patterns = include_path.map(|patterns| {
-				patterns.into_iter().map(Self::normalize_pattern).collect()
-			});
-			let exclude_patterns = exclude_path.map(|patterns| {
-				patterns.into_iter().map(Self::normalize_pattern).collect()
-			});
-			commits.retain(|commit| {
-				self.should_retain_commit(
-					commit,
-					&include_patterns,
-					&exclude_patterns,
-				)
-			});
+		let ids: Vec<Oid> = match since {
+			Some(since) => {
+				// Hiding a commit also hides its ancestors, so the rest of the
+				// history is not walked.
+				let mut is_old = |id: Oid| {
+					self.inner
+						.find_commit(id)
+						.is_ok_and(|commit| commit.time().seconds() < since)
+				};
+				revwalk
+					.with_hide_callback(&mut is_old)?
+					.filter_map(|id| id.ok())
+					.collect()
+			}
+			None => revwalk.filter_map(|id| id.ok()).collect(),
+		};
+		self.revwalk_cache.borrow_mut().insert(key, ids.clone());
+		Ok(ids)
+	}
+
+	/// Returns the message that describes the changes of a merge commit.
+	///
+	/// This is the title of the merged pull request (i.e. the body of the merge
+	/// commit) if it exists. Otherwise, the merged commits are squashed into a
+	/// message with the subject of the first merged commit and the subjects of
+	/// all merged commits in the body.
+	pub fn merge_commit_message(&self, commit: &Commit) -> Option<String> {
+		if commit.parent_count() < 2 {
+			return None;
+		}
+		if let Some(body) = commit.body().map(str::trim).filter(|v| !v.is_empty()) {
+			return Some(body.to_string());
+		}
+		let mut revwalk = self.inner.revwalk().ok()?;
+		revwalk
+			.set_sorting(Sort::TOPOLOGICAL | Sort::REVERSE)
+			.ok()?;
+		revwalk.push(commit.parent_id(1).ok()?).ok()?;
+		revwalk.hide(commit.parent_id(0).ok()?).ok()?;
+		let commits = revwalk
+			.filter_map(|id| self.inner.find_commit(id.ok()?).ok())
+			.collect::<Vec<_>>();
+		match commits.as_slice() {
+			[] => None,
+			[merged_commit] => merged_commit
+				.message()
+				.map(|message| message.trim_end().to_string()),
+			[first_commit, ..] => {
+				let summary = commits
+					.iter()
+					.filter_map(|commit| commit.summary())
+					.map(|summary| format!("* {summary}"))
+					.collect::<Vec<_>>()
+					.join("\n");
+				Some(format!(
+					"{}\n\n{summary}",
+					first_commit.summary().unwrap_or_default()
+				))
+			}
 		}
-		Ok(commits)
 	}
 
 	/// Normalizes the glob pattern to match the git diff paths.
@@ -190,37 +375,87 @@ impl Repository {
 		}
 	}
 
+	/// Returns the paths of the changed files of the commit.
+	///
+	/// Renames are not detected, so the previous paths of the renamed files are
+	/// also included.
+	pub fn commit_changed_files(&self, commit: &Commit) -> Vec<PathBuf> {
+		self.commit_files(commit, false)
+			.into_iter()
+			.map(|file| file.path)
+			.collect()
+	}
+
 	/// Returns the changed files of the commit.
 	///
+	/// If `detailed` is set, the renamed files are detected and the diff
+	/// statistics are calculated. Otherwise, only the paths and the kinds of
+	/// the changes are returned, which is much cheaper.
+	///
 	/// It uses a cache to speed up checks to store the changed files of the
 	/// commits under `./.git/git-cliff-core/changed_files_cache`. The speed-up
 	/// was measured to be around 260x for large repositories.
 	///
 	/// If the cache is not found, it calculates the changed files and adds them
-	/// to the cache via [`Self::commit_changed_files_no_cache`].
-	fn commit_changed_files(&self, commit: &Commit) -> Vec<PathBuf> {
-		// Cache key is generated from the repository path and commit id
-		let cache_key = format!("commit_id:{}", commit.id());
+	/// to the cache via [`Self::commit_files_no_cache`].
+	pub fn commit_files(&self, commit: &Commit, detailed: bool) -> Vec<FileChange> {
+		let cache_key = if detailed {
+			format!("commit_files:{}", commit.id())
+		} else {
+			format!("changed_files:{}", commit.id())
+		};
+		self.cached(cache_key, || self.commit_files_no_cache(commit, detailed))
+	}
 
+	/// Returns the patch ID of the commit, which is the same for the commits
+	/// that introduce the same changes (e.g. cherry-picked commits).
+	///
+	/// Returns `None` for the merge commits and the commits without changes.
+	/// The result is cached in the same way as [`Self::commit_files`].
+	pub fn commit_patch_id(&self, commit: &Commit) -> Option<String> {
+		if commit.parent_count() > 1 {
+			return None;
+		}
+		self.cached(format!("patch_id:{}", commit.id()), || {
+			let parent_tree = commit.parent(0).and_then(|parent| parent.tree()).ok();
+			let diff = self
+				.inner
+				.diff_tree_to_tree(
+					parent_tree.as_ref(),
+					commit.tree().ok().as_ref(),
+					None,
+				)
+				.ok()?;
+			if diff.deltas().len() == 0 {
+				return None;
+			}
+			diff.patchid(None).ok().map(|id| id.to_string())
+		})
+	}
+
+	/// Returns the cached value for the given key.
+	///
+	/// If the cache is not found, the value is calculated via the given
+	/// function and added to the cache.
+	fn cached<T: bincode::Encode + bincode::Decode>(
+		&self,
+		cache_key: String,
+		calculate: impl FnOnce() -> T,
+	) -> T {
 		// Check the cache first.
+		if let Ok(result) =
+			cacache::read_sync(&self.changed_files_cache_path, &cache_key)
 		{
-			if let Ok(result) =
-				cacache::read_sync(&self.changed_files_cache_path, &cache_key)
+			if let Ok((value, _)) =
+				bincode::decode_from_slice(&result, bincode::config::standard())
 			{
-				if let Ok((files, _)) =
-					bincode::decode_from_slice(&result, bincode::config::standard())
-				{
-					return files;
-				}
+				return value;
 			}
 		}
 
 		// If the cache is not found, calculate the result and set it to the cache.
-		let result = self.commit_changed_files_no_cache(commit);
-		match bincode::encode_to_vec(
-			self.commit_changed_files_no_cache(commit),
-			bincode::config::standard(),
-		) {
+		let result = calculate();
+		match bincode::encode_to_vec(&result, bincode::config::standard()) {
 			Ok(v) => {
 				if let Err(e) = cacache::write_sync_with_algo(
 					cacache::Algorithm::Xxh3,
@@ -241,48 +476,69 @@ impl Repository {
 
 	/// Calculate the changed files of the commit.
 	///
+	/// The commit is compared with its first parent, or with an empty tree if
+	/// it is the root commit.
+	///
+	/// See [`Self::commit_files`] for the meaning of `detailed`.
+	///
 	/// This function does not use the cache (directly calls git2).
-	fn commit_changed_files_no_cache(&self, commit: &Commit) -> Vec<PathBuf> {
-		let mut changed_files = Vec::new();
-		if let Ok(prev_commit) = commit.parent(0) {
-			// Compare the current commit with the previous commit to get the
-			// changed files.
-			// libgit2 does not provide a way to get the changed files directly, so
-			// the full diff is calculated here.
-			if let Ok(diff) = self.inner.diff_tree_to_tree(
-				commit.tree().ok().as_ref(),
-				prev_commit.tree().ok().as_ref(),
-				None,
-			) {
-				changed_files.extend(
-					diff.deltas().filter_map(|delta| {
-						delta.new_file().path().map(PathBuf::from)
-					}),
-				);
+	fn commit_files_no_cache(
+		&self,
+		commit: &Commit,
+		detailed: bool,
+	) -> Vec<FileChange> {
+		let parent_tree = commit.parent(0).and_then(|parent| parent.tree()).ok();
+		let mut diff = match self.inner.diff_tree_to_tree(
+			parent_tree.as_ref(),
+			commit.tree().ok().as_ref(),
+			None,
+		) {
+			Ok(diff) => diff,
+			Err(e) => {
+				error!("Failed to get the diff of {}: {e}", commit.id());
+				return Vec::new();
 			}
-		} else {
-			// If there is no parent, it is the first commit.
-			// So get all the files in the tree.
-			if let Ok(tree) = commit.tree() {
-				tree.walk(TreeWalkMode::PreOrder, |dir, entry| {
-					if entry.kind().expect("failed to get entry kind") !=
-						git2::ObjectType::Blob
-					{
-						return 0;
-					}
-					let name = entry.name().expect("failed to get entry name");
-					let entry_path = if dir == "," {
-						name.to_string()
-					} else {
-						format!("{dir}/{name}")
-					};
-					changed_files.push(entry_path.into());
-					0
-				})
-				.expect("failed to get the changed files of the first commit");
+		};
+		if detailed {
+			if let Err(e) =
+				diff.find_similar(Some(DiffFindOptions::new().renames(true)))
+			{
+				warn!("Failed to detect the renamed files of {}: {e}", commit.id());
 			}
 		}
-		changed_files
+		(0..diff.deltas().len())
+			.filter_map(|index| {
+				let delta = diff.get_delta(index)?;
+				let kind = match delta.status() {
+					Delta::Added | Delta::Copied => FileChangeKind::Added,
+					Delta::Deleted => FileChangeKind::Deleted,
+					Delta::Renamed => FileChangeKind::Renamed,
+					_ => FileChangeKind::Modified,
+				};
+				let old_path = delta.old_file().path().map(PathBuf::from);
+				let path = delta
+					.new_file()
+					.path()
+					.map(PathBuf::from)
+					.or(old_path.clone())?;
+				let (_, insertions, deletions) = if detailed {
+					Patch::from_diff(&diff, index)
+						.ok()
+						.flatten()
+						.and_then(|patch| patch.line_stats().ok())
+						.unwrap_or_default()
+				} else {
+					Default::default()
+				};
+				Some(FileChange {
+					path,
+					old_path: old_path.filter(|_| kind == FileChangeKind::Renamed),
+					kind,
+					insertions,
+					deletions,
+				})
+			})
+			.collect()
 	}
 
 	/// Returns the current tag.
@@ -322,6 +578,53 @@ impl Repository {
 		}
 	}
 
+	/// Returns whether if the tag with the given name exists.
+	pub fn tag_exists(&self, name: &str) -> bool {
+		self.inner
+			.find_reference(&format!("refs/tags/{name}"))
+			.is_ok()
+	}
+
+	/// Creates an annotated tag at `HEAD` with the given message.
+	///
+	/// If `sign` is set, the tag is created with `git tag --sign` so that the
+	/// configured GPG or SSH key of the user is used. The message is kept
+	/// verbatim so that lines starting with `#` (e.g. markdown headings) are
+	/// not stripped as comments.
+	pub fn create_tag(&self, name: &str, message: &str, sign: bool) -> Result<()> {
+		if self.tag_exists(name) {
+			return Err(Error::RepoError(format!("Tag already exists: {name}")));
+		}
+		if sign {
+			let mut child = Command::new("git")
+				.args([
+					"tag",
+					"--sign",
+					"--cleanup=verbatim",
+					"--file=-",
+					name,
+					"HEAD",
+				])
+				.current_dir(self.path())
+				.stdin(Stdio::piped())
+				.spawn()?;
+			if let Some(mut stdin) = child.stdin.take() {
+				stdin.write_all(message.as_bytes())?;
+			}
+			let status = child.wait()?;
+			if !status.success() {
+				return Err(Error::RepoError(format!(
+					"Failed to create the signed tag ({status})"
+				)));
+			}
+		} else {
+			let head = self.inner.head()?.peel(ObjectType::Commit)?;
+			self.inner
+				.tag(name, &head, &self.inner.signature()?, message, false)?;
+		}
+		Ok(())
+	}
+
 	/// Returns the commit object of the given ID.
 	pub fn find_commit(&self, id: &str) -> Option<Commit> {
 		if let Ok(oid) = Oid::from_str(id) {
@@ -349,13 +652,63 @@ impl Repository {
 			head_commit.id() == tag_commit.id())
 	}
 
+	/// Returns the virtual tags of the release branches that match the pattern.
+	///
+	/// The release point of a branch is its merge-base with the current branch.
+	/// If the pattern has a capture group, it is used as the tag name instead
+	/// of the branch name.
+	fn release_branch_tags(
+		&self,
+		pattern: &Regex,
+		head_commit: &Commit,
+	) -> Result<Vec<(Commit<'_>, Tag)>> {
+		let mut tags: Vec<(Commit, Tag)> = Vec::new();
+		for branch in self.inner.branches(None)? {
+			let (branch, branch_type) = branch?;
+			let Some(name) = branch.name()? else {
+				continue;
+			};
+			let name = match branch_type {
+				BranchType::Local => name,
+				BranchType::Remote => match name.split_once('/') {
+					Some((_, name)) => name,
+					None => continue,
+				},
+			};
+			let Some(tag_name) = pattern
+				.captures(name)
+				.and_then(|captures| captures.get(1).or_else(|| captures.get(0)))
+				.map(|v| v.as_str().to_string())
+			else {
+				continue;
+			};
+			if tags.iter().any(|(_, tag)| tag.name == tag_name) {
+				continue;
+			}
+			let branch_commit = branch.get().peel_to_commit()?;
+			let Ok(merge_base) =
+				self.inner.merge_base(head_commit.id(), branch_commit.id())
+			else {
+				warn!("No merge-base found for the release branch: {name}");
+				continue;
+			};
+			tags.push((self.inner.find_commit(merge_base)?, Tag {
+				name:    tag_name,
+				message: None,
+			}));
+		}
+		Ok(tags)
+	}
+
 	/// Parses and returns a commit-tag map.
 	///
-	/// It collects lightweight and annotated tags.
+	/// It collects lightweight and annotated tags, and the virtual tags of the
+	/// release branches that match `release_branch_pattern`.
 	pub fn tags(
 		&self,
 		pattern: &Option<Regex>,
-		topo_order: bool,
+		release_branch_pattern: &Option<Regex>,
+		sort: TagSort,
 		use_branch_tags: bool,
 	) -> Result<IndexMap<String, Tag>> {
 		let mut tags: Vec<(Commit, Tag)> = Vec::new();
@@ -401,8 +754,44 @@ impl Repository {
 				}
 			}
 		}
-		if !topo_order {
-			tags.sort_by(|a, b| a.0.time().seconds().cmp(&b.0.time().seconds()));
+		if let Some(release_branch_pattern) = release_branch_pattern {
+			for (commit, tag) in
+				self.release_branch_tags(release_branch_pattern, &head_commit)?
+			{
+				if !tags
+					.iter()
+					.any(|(tag_commit, _)| tag_commit.id() == commit.id())
+				{
+					tags.push((commit, tag));
+				}
+			}
+		}
+		match sort {
+			TagSort::Time => {
+				tags.sort_by_key(|(commit, _)| commit.time().seconds());
+			}
+			TagSort::Topological => {}
+			TagSort::Semver => {
+				// Tags that are not semantic versions have no place in the order.
+				let mut versions = Vec::new();
+				for (commit, tag) in tags {
+					match parse_version(&tag.name) {
+						Ok((_, version)) => versions.push((version, commit, tag)),
+						Err(e) => {
+							warn!(
+								"Skipping the tag {} since it is not a semantic \
+								 version: {e}",
+								tag.name
+							);
+						}
+					}
+				}
+				versions.sort_by(|(a, ..), (b, ..)| a.cmp_precedence(b));
+				tags = versions
+					.into_iter()
+					.map(|(_, commit, tag)| (commit, tag))
+					.collect();
+			}
 		}
 		Ok(tags
 			.into_iter()
@@ -566,6 +955,26 @@ mod test {
 		.to_string())
 	}
 
+	/// Returns `true` if `ssh-keygen` is available and `git` supports signing
+	/// with SSH keys (2.34 or later).
+	fn supports_ssh_signing() -> bool {
+		let ssh_keygen = Command::new("ssh-keygen").arg("-?").output().is_ok();
+		let git_version = Command::new("git")
+			.arg("--version")
+			.output()
+			.ok()
+			.and_then(|output| {
+				let output = String::from_utf8(output.stdout).ok()?;
+				let mut version = output
+					.trim()
+					.strip_prefix("git version ")?
+					.split('.')
+					.map(|v| v.parse::<u32>().ok());
+				Some((version.next()??, version.next()??))
+			});
+		ssh_keygen && git_version.is_some_and(|version| version >= (2, 34))
+	}
+
 	fn get_repository() -> Result<Repository> {
 		Repository::init(
 			PathBuf::from(env!("CARGO_MANIFEST_DIR"))
@@ -596,7 +1005,7 @@ mod test {
 	#[test]
 	fn get_latest_commit() -> Result<()> {
 		let repository = get_repository()?;
-		let commits = repository.commits(None, None, None)?;
+		let commits = repository.commits(None, None, None, false, None)?;
 		let last_commit =
 			AppCommit::from(&commits.first().expect("no commits found").clone());
 		assert_eq!(get_last_commit_hash()?, last_commit.id);
@@ -615,7 +1024,7 @@ mod test {
 	#[test]
 	fn get_latest_tag() -> Result<()> {
 		let repository = get_repository()?;
-		let tags = repository.tags(&None, false, false)?;
+		let tags = repository.tags(&None, &None, TagSort::Time, false)?;
 		let latest = tags.last().expect("no tags found").1.name.clone();
 		assert_eq!(get_last_tag()?, latest);
 
@@ -627,7 +1036,7 @@ mod test {
 	#[test]
 	fn git_tags() -> Result<()> {
 		let repository = get_repository()?;
-		let tags = repository.tags(&None, true, false)?;
+		let tags = repository.tags(&None, &None, TagSort::Topological, false)?;
 		assert_eq!(
 			tags.get("2b8b4d3535f29231e05c3572e919634b9af907b6")
 				.expect(
@@ -650,7 +1059,8 @@ mod test {
 				Regex::new("^v[0-9]+\\.[0-9]+\\.[0-9]$")
 					.expect("the regex is not valid"),
 			),
-			true,
+			&None,
+			TagSort::Topological,
 			false,
 		)?;
 		assert_eq!(
@@ -713,7 +1123,7 @@ mod test {
 		let repository = get_repository()?;
 		// a close descendant of the root commit
 		let range = Some("eea3914c7ab07472841aa85c36d11bdb2589a234");
-		let commits = repository.commits(range, None, None)?;
+		let commits = repository.commits(range, None, None, false, None)?;
 		let root_commit =
 			AppCommit::from(&commits.last().expect("no commits found").clone());
 		assert_eq!(get_root_commit_hash()?, root_commit.id);
@@ -849,6 +1259,415 @@ mod test {
 		last_commit
 	}
 
+	#[test]
+	fn changed_files_of_root_commit() {
+		let (repo, _temp_dir) = create_temp_repo();
+		let commit = create_commit_with_files(&repo, vec![
+			("initial.txt", "initial content"),
+			("dir/initial.txt", "initial content"),
+		]);
+		assert_eq!(
+			vec![
+				PathBuf::from("dir/initial.txt"),
+				PathBuf::from("initial.txt")
+			],
+			repo.commit_files_no_cache(&commit, false)
+				.into_iter()
+				.map(|file| file.path)
+				.collect::<Vec<_>>()
+		);
+	}
+
+	#[test]
+	fn changed_files_with_stats() -> Result<()> {
+		let (repo, _temp_dir) = create_temp_repo();
+		create_commit_with_files(&repo, vec![
+			("README.md", "line 1\nline 2\n"),
+			("old.txt", "same content\n"),
+			("removed.txt", "removed\n"),
+		]);
+		std::fs::rename(repo.path.join("old.txt"), repo.path.join("new.txt"))?;
+		std::fs::remove_file(repo.path.join("removed.txt"))?;
+		let commit = create_commit_with_files(&repo, vec![
+			("README.md", "line 1\nline 3\nline 4\n"),
+			("src/lib.rs", "fn main() {}\n"),
+		]);
+		assert_eq!(
+			vec![
+				FileChange {
+					path:       PathBuf::from("README.md"),
+					old_path:   None,
+					kind:       FileChangeKind::Modified,
+					insertions: 2,
+					deletions:  1,
+				},
+				FileChange {
+					path:       PathBuf::from("new.txt"),
+					old_path:   Some(PathBuf::from("old.txt")),
+					kind:       FileChangeKind::Renamed,
+					insertions: 0,
+					deletions:  0,
+				},
+				FileChange {
+					path:       PathBuf::from("removed.txt"),
+					old_path:   None,
+					kind:       FileChangeKind::Deleted,
+					insertions: 0,
+					deletions:  1,
+				},
+				FileChange {
+					path:       PathBuf::from("src/lib.rs"),
+					old_path:   None,
+					kind:       FileChangeKind::Added,
+					insertions: 1,
+					deletions:  0,
+				},
+			],
+			repo.commit_files(&commit, true)
+		);
+		assert_eq!(
+			vec![
+				PathBuf::from("README.md"),
+				PathBuf::from("new.txt"),
+				PathBuf::from("old.txt"),
+				PathBuf::from("removed.txt"),
+				PathBuf::from("src/lib.rs"),
+			],
+			repo.commit_changed_files(&commit)
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn changed_files_without_stats() -> Result<()> {
+		let (repo, _temp_dir) = create_temp_repo();
+		create_commit_with_files(&repo, vec![
+			("README.md", "line 1\n"),
+			("old.txt", "same content\n"),
+		]);
+		std::fs::rename(repo.path.join("old.txt"), repo.path.join("new.txt"))?;
+		let commit =
+			create_commit_with_files(&repo, vec![("README.md", "line 2\n")]);
+		assert_eq!(
+			vec![
+				(PathBuf::from("README.md"), FileChangeKind::Modified),
+				(PathBuf::from("new.txt"), FileChangeKind::Added),
+				(PathBuf::from("old.txt"), FileChangeKind::Deleted),
+			],
+			repo.commit_files(&commit, false)
+				.into_iter()
+				.map(|file| {
+					assert_eq!(
+						(None, 0, 0),
+						(file.old_path, file.insertions, file.deletions)
+					);
+					(file.path, file.kind)
+				})
+				.collect::<Vec<_>>()
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn patch_id_of_same_changes() -> Result<()> {
+		let (repo, _temp_dir) = create_temp_repo();
+		let first_commit =
+			create_commit_with_files(&repo, vec![("feature.txt", "feature")]);
+		std::fs::remove_file(repo.path.join("feature.txt"))?;
+		let second_commit =
+			create_commit_with_files(&repo, vec![("other.txt", "other")]);
+		let third_commit =
+			create_commit_with_files(&repo, vec![("feature.txt", "feature")]);
+		let patch_id = repo.commit_patch_id(&first_commit);
+		assert!(patch_id.is_some());
+		assert_ne!(patch_id, repo.commit_patch_id(&second_commit));
+		assert_eq!(patch_id, repo.commit_patch_id(&third_commit));
+		Ok(())
+	}
+
+	#[test]
+	fn resolve_signatures_with_mailmap() -> Result<()> {
+		let (repo, temp_dir) = create_temp_repo();
+		let commit = create_commit_with_files(&repo, vec![("a.txt", "a")]);
+		std::fs::write(
+			temp_dir.path().join(".mailmap"),
+			"Repo Name <repo@gmail.com> <test@gmail.com>\n",
+		)?;
+		let mut repo = Repository::init(temp_dir.path().to_path_buf())?;
+		let (author, committer) = repo.commit_signatures(&commit);
+		assert_eq!(Some(String::from("Repo Name")), author.name);
+		assert_eq!(Some(String::from("repo@gmail.com")), author.email);
+		assert_eq!(author.name, committer.name);
+
+		std::fs::create_dir(temp_dir.path().join("contrib"))?;
+		std::fs::write(
+			temp_dir.path().join("contrib").join("mailmap"),
+			"Config Name <test@gmail.com>\n",
+		)?;
+		repo.add_mailmap(Path::new("contrib/mailmap"))?;
+		let (author, _) = repo.commit_signatures(&commit);
+		assert_eq!(Some(String::from("Config Name")), author.name);
+		assert_eq!(Some(String::from("test@gmail.com")), author.email);
+		Ok(())
+	}
+
+	#[test]
+	fn first_parent_history() -> Result<()> {
+		let (repo, _temp_dir) = create_temp_repo();
+		let git = |args: &[&str]| {
+			let output = Command::new("git")
+				.args(args)
+				.current_dir(&repo.path)
+				.output()
+				.expect("failed to execute git");
+			assert!(output.status.success(), "git failed {:?}", output);
+		};
+		let commit = |message: &str| {
+			git(&["commit", "--no-gpg-sign", "--allow-empty", "-m", message]);
+		};
+		commit("feat: initial commit");
+		git(&["checkout", "-b", "feature"]);
+		commit("feat: add the parser");
+		commit("wip: handle the errors");
+		git(&["checkout", "-"]);
+		git(&[
+			"merge",
+			"--no-ff",
+			"--no-gpg-sign",
+			"-m",
+			"Merge pull request #1 from user/feature",
+			"-m",
+			"feat: support parsing",
+			"feature",
+		]);
+		git(&["checkout", "-b", "fix"]);
+		commit("fix: handle the empty input");
+		git(&["checkout", "-"]);
+		git(&["merge", "--no-ff", "--no-gpg-sign", "--no-edit", "fix"]);
+
+		let commits = repo.commits(None, None, None, true, None)?;
+		assert_eq!(3, commits.len());
+		assert_eq!(
+			vec![
+				Some(String::from("fix: handle the empty input")),
+				Some(String::from("feat: support parsing")),
+				None
+			],
+			commits
+				.iter()
+				.map(|commit| repo.merge_commit_message(commit))
+				.collect::<Vec<_>>()
+		);
+		assert_eq!(6, repo.commits(None, None, None, false, None)?.len());
+
+		git(&["checkout", "-b", "refactor"]);
+		commit("refactor: split the parser");
+		commit("refactor: rename the modules");
+		git(&["checkout", "-"]);
+		git(&["merge", "--no-ff", "--no-gpg-sign", "--no-edit", "refactor"]);
+		let head = repo.inner.head()?.peel_to_commit()?;
+		assert_eq!(
+			Some(String::from(
+				"refactor: split the parser\n\n* refactor: split the parser\n* \
+				 refactor: rename the modules"
+			)),
+			repo.merge_commit_message(&head)
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn tags_in_semver_order() -> Result<()> {
+		let (repo, _temp_dir) = create_temp_repo();
+		for tag in [
+			"v1.0.0",
+			"v2.0.0",
+			"v2.1.0",
+			"nightly",
+			"v2.1.0-rc.1",
+			"v1.4.9",
+			"v2.1.0-beta.2",
+			"v2.1.0-beta.11",
+		] {
+			create_commit_with_files(&repo, vec![(tag, tag)]);
+			let output = Command::new("git")
+				.args(["tag", tag])
+				.current_dir(&repo.path)
+				.output()
+				.expect("failed to execute git tag");
+			assert!(output.status.success(), "git tag failed {:?}", output);
+		}
+		let tags = repo.tags(&None, &None, TagSort::Semver, false)?;
+		assert_eq!(
+			vec![
+				"v1.0.0",
+				"v1.4.9",
+				"v2.0.0",
+				"v2.1.0-beta.2",
+				"v2.1.0-beta.11",
+				"v2.1.0-rc.1",
+				"v2.1.0",
+			],
+			tags.values()
+				.map(|tag| tag.name.as_str())
+				.collect::<Vec<_>>()
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn release_branches_as_tags() -> Result<()> {
+		let (repo, _temp_dir) = create_temp_repo();
+		let git = |args: &[&str]| {
+			let output = Command::new("git")
+				.args(args)
+				.current_dir(&repo.path)
+				.output()
+				.expect("failed to execute git");
+			assert!(output.status.success(), "git failed {:?}", output);
+		};
+		let first_commit = create_commit_with_files(&repo, vec![("a.txt", "a")]);
+		git(&["tag", "v1.0.0"]);
+		let second_commit = create_commit_with_files(&repo, vec![("b.txt", "b")]);
+		git(&["checkout", "-b", "release/2024.10"]);
+		create_commit_with_files(&repo, vec![("c.txt", "c")]);
+		git(&["checkout", "-"]);
+		let third_commit = create_commit_with_files(&repo, vec![("d.txt", "d")]);
+		create_commit_with_files(&repo, vec![("e.txt", "e")]);
+		git(&["branch", "release/2024.11", "HEAD~1"]);
+		git(&["branch", "feature"]);
+
+		let tags = repo.tags(
+			&None,
+			&Some(Regex::new("^release/(.*)$").expect("the regex is not valid")),
+			TagSort::Time,
+			false,
+		)?;
+		assert_eq!(
+			vec![
+				(first_commit.id().to_string(), "v1.0.0"),
+				(second_commit.id().to_string(), "2024.10"),
+				(third_commit.id().to_string(), "2024.11"),
+			],
+			tags.iter()
+				.map(|(id, tag)| (id.clone(), tag.name.as_str()))
+				.collect::<Vec<_>>()
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn create_annotated_tag() -> Result<()> {
+		let (repo, _temp_dir) = create_temp_repo();
+		let commit = create_commit_with_files(&repo, vec![("a.txt", "a")]);
+		repo.create_tag("v1.0.0", "### Features\n\n- Add a", false)?;
+		let tags = repo.tags(&None, &None, TagSort::Time, false)?;
+		let tag = tags.get(&commit.id().to_string()).expect("tag not found");
+		assert_eq!("v1.0.0", tag.name);
+		assert_eq!(Some(String::from("### Features\n\n- Add a")), tag.message);
+		assert!(repo.create_tag("v1.0.0", "", false).is_err());
+		Ok(())
+	}
+
+	#[test]
+	fn create_signed_tag() -> Result<()> {
+		if !supports_ssh_signing() {
+			eprintln!(
+				"Skipping the test since ssh-keygen is not available or git does \
+				 not support signing with SSH keys"
+			);
+			return Ok(());
+		}
+		let (repo, _temp_dir) = create_temp_repo();
+		let key = repo.path.join(".git").join("signing_key");
+		let output = Command::new("ssh-keygen")
+			.args(["-q", "-t", "ed25519", "-N", "", "-f"])
+			.arg(&key)
+			.output()
+			.expect("failed to execute ssh-keygen");
+		assert!(output.status.success(), "ssh-keygen failed {:?}", output);
+		for (name, value) in [
+			("gpg.format", "ssh"),
+			("user.signingkey", key.to_str().expect("invalid key path")),
+		] {
+			let output = Command::new("git")
+				.args(["config", name, value])
+				.current_dir(&repo.path)
+				.output()
+				.expect("failed to execute git config");
+			assert!(output.status.success(), "git config failed {:?}", output);
+		}
+		let commit = create_commit_with_files(&repo, vec![("a.txt", "a")]);
+		repo.create_tag("v1.0.0", "### Features\n\n- Add a", true)?;
+		let tags = repo.tags(&None, &None, TagSort::Time, false)?;
+		let tag = tags.get(&commit.id().to_string()).expect("tag not found");
+		assert_eq!("v1.0.0", tag.name);
+		assert_eq!(Some(String::from("### Features\n\n- Add a")), tag.message);
+		Ok(())
+	}
+
+	#[test]
+	fn commits_with_cached_revwalk() -> Result<()> {
+		let (repo, _temp_dir) = create_temp_repo();
+		let first_commit =
+			create_commit_with_files(&repo, vec![("core/lib.rs", "core")]);
+		let second_commit =
+			create_commit_with_files(&repo, vec![("cli/main.rs", "cli")]);
+
+		let core_commits = repo.commits(
+			None,
+			Some(vec![Pattern::new("core/**")?]),
+			None,
+			false,
+			None,
+		)?;
+		let cli_commits = repo.commits(
+			None,
+			None,
+			Some(vec![Pattern::new("core/**")?]),
+			false,
+			None,
+		)?;
+		assert_eq!(
+			vec![first_commit.id()],
+			core_commits.iter().map(|c| c.id()).collect::<Vec<_>>()
+		);
+		assert_eq!(
+			vec![second_commit.id()],
+			cli_commits.iter().map(|c| c.id()).collect::<Vec<_>>()
+		);
+		assert_eq!(1, repo.revwalk_cache.borrow().len());
+		Ok(())
+	}
+
+	#[test]
+	fn commits_since() -> Result<()> {
+		let (repo, _temp_dir) = create_temp_repo();
+		for date in [
+			"2022-01-01T00:00:00Z",
+			"2023-01-01T00:00:00Z",
+			"2024-01-01T00:00:00Z",
+		] {
+			let output = Command::new("git")
+				.args(["commit", "--no-gpg-sign", "--allow-empty", "-m", date])
+				.env("GIT_COMMITTER_DATE", date)
+				.current_dir(&repo.path)
+				.output()
+				.expect("failed to execute git commit");
+			assert!(output.status.success(), "git commit failed {:?}", output);
+		}
+		assert_eq!(3, repo.commits(None, None, None, false, None)?.len());
+		assert_eq!(
+			vec!["2024-01-01T00:00:00Z", "2023-01-01T00:00:00Z"],
+			repo.commits(None, None, None, false, Some(1672531200))?
+				.iter()
+				.filter_map(|commit| commit.message())
+				.map(str::trim)
+				.collect::<Vec<_>>()
+		);
+		Ok(())
+	}
+
 	#[test]
 	fn test_should_retain_commit() {
 		let (repo, _temp_dir) = create_temp_repo();
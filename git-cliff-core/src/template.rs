@@ -19,6 +19,22 @@ use tera::{
 	Value,
 };
 
+/// Template variables that are deprecated in favor of `commit.remote`.
+pub const DEPRECATED_VARIABLES: &[&str] = &[
+	"commit.github",
+	"commit.gitea",
+	"commit.gitlab",
+	"commit.bitbucket",
+];
+
+/// Variables that are bound in a template scope, mapped to their location in
+/// the context if it is known.
+type Scope = HashMap<String, Option<Vec<String>>>;
+
+/// Visitor of the template variables that receives their name, their
+/// location in the context if it is known and whether they are guarded.
+type Visitor<'a> = dyn FnMut(&str, Option<Vec<String>>, bool) + 'a;
+
 /// Wrapper for [`Tera`].
 #[derive(Debug)]
 pub struct Template {
@@ -143,6 +159,228 @@ impl Template {
 		Ok(variables.into_iter().collect())
 	}
 
+	/// Returns the variables that are used in the template but are not defined
+	/// in the given context.
+	///
+	/// Loop variables are resolved via their containers while the variables
+	/// that are assigned in the template (e.g. via `set`) are skipped.
+	pub fn get_undefined_variables(&self, context: &Value) -> Result<Vec<String>> {
+		let mut undefined = HashSet::new();
+		self.walk_variables(&mut |name, location, guarded| {
+			if let Some(location) = location.filter(|_| !guarded) {
+				if !Self::is_defined(&location, context) {
+					undefined.insert(name.to_string());
+				}
+			}
+		})?;
+		let mut undefined: Vec<String> = undefined.into_iter().collect();
+		undefined.sort();
+		Ok(undefined)
+	}
+
+	/// Returns `true` if one of the given fields is accessed on a variable in
+	/// the template, e.g. `commit.files` or `commit.stats.insertions` for
+	/// `files` and `stats`.
+	///
+	/// The fields are matched with the resolved location of the variables so
+	/// that the loop variables and the assigned variables are also covered.
+	pub fn accesses_field(&self, fields: &[&str]) -> Result<bool> {
+		let mut accessed = false;
+		self.walk_variables(&mut |name, location, _| {
+			let path = location.unwrap_or_else(|| {
+				name.split(['.', '[']).map(String::from).collect()
+			});
+			accessed |= path.iter().skip(1).any(|v| fields.contains(&v.as_str()));
+		})?;
+		Ok(accessed)
+	}
+
+	/// Visits the variables that are used in the template.
+	///
+	/// Variables are guarded if they have the `default` filter or are checked
+	/// via the `defined` and `undefined` tests.
+	fn walk_variables(&self, visit: &mut Visitor) -> Result<()> {
+		let mut scope = Scope::from([(String::from("__tera_context"), None)]);
+		let ast = &self.tera.get_template(&self.name)?.ast;
+		Self::walk_nodes(ast, &mut scope, visit);
+		Ok(())
+	}
+
+	/// Recursively visits the variables in the given nodes.
+	fn walk_nodes(nodes: &[ast::Node], scope: &mut Scope, visit: &mut Visitor) {
+		for node in nodes {
+			match node {
+				ast::Node::VariableBlock(_, expr) => {
+					Self::walk_expr(expr, scope, visit);
+				}
+				ast::Node::Set(_, set) => {
+					Self::walk_expr(&set.value, scope, visit);
+					scope.insert(set.key.clone(), None);
+				}
+				ast::Node::Block(_, block, _) => {
+					Self::walk_nodes(&block.body, &mut scope.clone(), visit);
+				}
+				ast::Node::FilterSection(_, section, _) => {
+					Self::walk_nodes(&section.body, &mut scope.clone(), visit);
+				}
+				ast::Node::MacroDefinition(_, def, _) => {
+					let mut macro_scope = scope.clone();
+					for arg in def.args.keys() {
+						macro_scope.insert(arg.clone(), None);
+					}
+					Self::walk_nodes(&def.body, &mut macro_scope, visit);
+				}
+				ast::Node::Forloop(_, forloop, _) => {
+					Self::walk_expr(&forloop.container, scope, visit);
+					let container = match &forloop.container.val {
+						ast::ExprVal::Ident(v) => Self::resolve_variable(v, scope)
+							.map(|mut path| {
+								path.push(String::from("*"));
+								path
+							}),
+						_ => None,
+					};
+					let mut loop_scope = scope.clone();
+					loop_scope.insert(String::from("loop"), None);
+					if let Some(key) = &forloop.key {
+						loop_scope.insert(key.clone(), None);
+					}
+					loop_scope.insert(forloop.value.clone(), container);
+					Self::walk_nodes(&forloop.body, &mut loop_scope, visit);
+					if let Some(empty_body) = &forloop.empty_body {
+						Self::walk_nodes(empty_body, &mut scope.clone(), visit);
+					}
+				}
+				ast::Node::If(cond, _) => {
+					for (_, expr, nodes) in &cond.conditions {
+						Self::walk_expr(expr, scope, visit);
+						Self::walk_nodes(nodes, scope, visit);
+					}
+					if let Some((_, nodes)) = &cond.otherwise {
+						Self::walk_nodes(nodes, scope, visit);
+					}
+				}
+				_ => {}
+			}
+		}
+	}
+
+	/// Visits the variables used in the given expression.
+	fn walk_expr(expr: &ast::Expr, scope: &Scope, visit: &mut Visitor) {
+		let mut visit_variable = |name: &str, guarded: bool| {
+			visit(name, Self::resolve_variable(name, scope), guarded);
+		};
+		match &expr.val {
+			ast::ExprVal::Ident(v) => {
+				visit_variable(v, expr.has_default_filter());
+			}
+			ast::ExprVal::StringConcat(concat) => {
+				for value in &concat.values {
+					if let ast::ExprVal::Ident(v) = value {
+						visit_variable(v, false);
+					}
+				}
+			}
+			ast::ExprVal::Test(test) => {
+				visit_variable(
+					&test.ident,
+					["defined", "undefined"].contains(&test.name.as_str()),
+				);
+				for arg in &test.args {
+					Self::walk_expr(arg, scope, visit);
+				}
+			}
+			ast::ExprVal::Math(math) => {
+				Self::walk_expr(&math.lhs, scope, visit);
+				Self::walk_expr(&math.rhs, scope, visit);
+			}
+			ast::ExprVal::Logic(logic) => {
+				Self::walk_expr(&logic.lhs, scope, visit);
+				Self::walk_expr(&logic.rhs, scope, visit);
+			}
+			ast::ExprVal::In(expr) => {
+				Self::walk_expr(&expr.lhs, scope, visit);
+				Self::walk_expr(&expr.rhs, scope, visit);
+			}
+			ast::ExprVal::Array(values) => {
+				for value in values {
+					Self::walk_expr(value, scope, visit);
+				}
+			}
+			ast::ExprVal::MacroCall(call) => {
+				for arg in call.args.values() {
+					Self::walk_expr(arg, scope, visit);
+				}
+			}
+			ast::ExprVal::FunctionCall(call) => {
+				for arg in call.args.values() {
+					Self::walk_expr(arg, scope, visit);
+				}
+			}
+			_ => {}
+		}
+		for filter in &expr.filters {
+			for arg in filter.args.values() {
+				Self::walk_expr(arg, scope, visit);
+			}
+		}
+	}
+
+	/// Resolves the location of the given variable in the context.
+	///
+	/// Array indices are represented as `*`. Returns `None` if the location
+	/// cannot be known, e.g. for the variables that are assigned in the
+	/// template.
+	fn resolve_variable(name: &str, scope: &Scope) -> Option<Vec<String>> {
+		let mut path = Vec::new();
+		for part in name.split('.') {
+			let mut segments = part.split('[');
+			path.push(segments.next().unwrap_or_default().to_string());
+			for index in segments {
+				let index = index.trim_end_matches(']');
+				if index.chars().all(|c| c.is_ascii_digit()) {
+					path.push(String::from("*"));
+				} else {
+					let key = index
+						.strip_prefix(['"', '\''])
+						.and_then(|v| v.strip_suffix(['"', '\'']))?;
+					path.push(key.to_string());
+				}
+			}
+		}
+		match scope.get(&path[0]) {
+			Some(location) => location.clone().map(|mut location| {
+				location.extend(path.into_iter().skip(1));
+				location
+			}),
+			None => Some(path),
+		}
+	}
+
+	/// Returns `true` if the given location exists in the context.
+	///
+	/// Empty arrays and `null` values are treated as defined since their
+	/// contents cannot be known.
+	fn is_defined(path: &[String], context: &Value) -> bool {
+		let mut value = context;
+		for segment in path {
+			value = match value {
+				Value::Array(values) if segment == "*" => match values.first() {
+					Some(v) => v,
+					None => return true,
+				},
+				Value::Object(_) if segment == "*" => value,
+				Value::Object(map) => match map.get(segment) {
+					Some(v) => v,
+					None => return false,
+				},
+				Value::Null => return true,
+				_ => return segment == "*",
+			};
+		}
+		true
+	}
+
 	/// Returns `true` if the template contains one of the given variables.
 	pub(crate) fn contains_variable(&self, variables: &[&str]) -> bool {
 		variables
@@ -193,6 +431,7 @@ mod test {
 	use super::*;
 	use crate::{
 		commit::Commit,
+		commit::DiffStats,
 		release::Release,
 	};
 	use regex::Regex;
@@ -201,6 +440,9 @@ mod test {
 		Release {
 			version: Some(String::from("1.0")),
 			message: None,
+			stats: DiffStats::default(),
+			breaking_changes: vec![],
+			prereleases: vec![],
 			extra: None,
 			commits: vec![
 				Commit::new(
@@ -299,6 +541,49 @@ mod test {
 		Ok(())
 	}
 
+	#[test]
+	fn find_undefined_variables() -> Result<()> {
+		let template = r#"
+		{% set title = "Release" %}{{ title }} {{ versoin }}
+		{% for commit in commits %}
+		{{ commit.group }} {{ commit.grup }} {{ loop.index }}
+		{% for link in commit.links %}{{ link.href }}{% endfor %}
+		{% endfor %}
+		{% if extra is defined %}{{ extra.anything }}{% endif %}
+		{{ previous | default(value="") }}"#;
+		let template = Template::new("test", template.to_string(), false)?;
+		let context = serde_json::to_value(get_fake_release_data())?;
+		assert_eq!(
+			vec![String::from("commit.grup"), String::from("versoin")],
+			template.get_undefined_variables(&context)?
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn find_accessed_fields() -> Result<()> {
+		let fields = ["files", "stats"];
+		let accesses_field = |template: &str| -> Result<bool> {
+			Template::new("test", template.to_string(), false)?
+				.accesses_field(&fields)
+		};
+		assert!(!accesses_field(
+			"## Changed files and stats\n{{ version }}"
+		)?);
+		assert!(!accesses_field("{% set files = 1 %}{{ files }}")?);
+		assert!(accesses_field(
+			"{% for commit in commits %}{{ commit.stats.insertions }}{% endfor %}"
+		)?);
+		assert!(accesses_field(
+			"{% for commit in commits %}{% for file in commit.files %}{{ file.path \
+			 }}{% endfor %}{% endfor %}"
+		)?);
+		assert!(accesses_field(
+			"{% set commit = commits | first %}{{ commit.files | length }}"
+		)?);
+		Ok(())
+	}
+
 	#[test]
 	fn test_upper_first_filter() -> Result<()> {
 		let template =
@@ -0,0 +1,617 @@
+use crate::config::{
+	VersionFile,
+	VersionFileFormat,
+};
+use crate::error::{
+	Error,
+	Result,
+};
+use lazy_regex::Regex;
+use std::fs;
+use std::ops::Range;
+use std::path::{
+	Path,
+	PathBuf,
+};
+use toml_edit::{
+	DocumentMut,
+	Item,
+	TableLike,
+	Value,
+};
+
+/// Placeholder of the version in the version file patterns.
+const VERSION_PLACEHOLDER: &str = "{version}";
+
+/// Regex that is substituted for the version placeholder.
+const VERSION_REGEX: &str =
+	r"(?P<version>\d+\.\d+\.\d+(?:-[0-9A-Za-z.-]+)?(?:\+[0-9A-Za-z.-]+)?)";
+
+/// Dependency tables of the Cargo manifests.
+const CARGO_DEPENDENCY_TABLES: &[&str] =
+	&["dependencies", "dev-dependencies", "build-dependencies"];
+
+/// Sets the given version to the item while keeping its decoration.
+fn set_version(item: &mut Item, version: &str) -> bool {
+	match item.as_value_mut() {
+		Some(value) if value.is_str() => {
+			let decor = value.decor().clone();
+			*value = Value::from(version);
+			*value.decor_mut() = decor;
+			true
+		}
+		_ => false,
+	}
+}
+
+/// Returns the mutable table-like item at the given path.
+fn get_table_mut<'a>(
+	document: &'a mut DocumentMut,
+	path: &[&str],
+) -> Option<&'a mut dyn TableLike> {
+	path.iter()
+		.try_fold(document.as_item_mut(), |item, key| {
+			item.as_table_like_mut().and_then(|v| v.get_mut(key))
+		})
+		.and_then(Item::as_table_like_mut)
+}
+
+/// Sets the version of the table at the given path.
+fn set_table_version(
+	document: &mut DocumentMut,
+	path: &[&str],
+	version: &str,
+) -> bool {
+	get_table_mut(document, path)
+		.and_then(|table| table.get_mut("version"))
+		.is_some_and(|item| set_version(item, version))
+}
+
+/// Updates the versions of the dependencies to the given packages.
+///
+/// Version operators such as `=` or `^` are kept.
+fn set_dependency_versions(
+	table: &mut dyn TableLike,
+	packages: &[String],
+	version: &str,
+) {
+	for (name, dependency) in table.iter_mut() {
+		let Some(dependency) = dependency.as_table_like_mut() else {
+			continue;
+		};
+		let package = dependency
+			.get("package")
+			.and_then(Item::as_str)
+			.unwrap_or(name.get())
+			.to_string();
+		if !packages.contains(&package) || !dependency.contains_key("path") {
+			continue;
+		}
+		if let Some(item) = dependency.get_mut("version") {
+			let operator = item
+				.as_str()
+				.map(|v| {
+					v.chars()
+						.take_while(|c| !c.is_ascii_digit())
+						.collect::<String>()
+				})
+				.unwrap_or_default();
+			set_version(item, &format!("{operator}{version}"));
+		}
+	}
+}
+
+/// Updates the versions of the workspace dependencies in the given manifest.
+fn update_cargo_dependencies(
+	contents: &str,
+	packages: &[String],
+	version: &str,
+) -> Result<String> {
+	let mut document = contents.parse::<DocumentMut>()?;
+	let paths = CARGO_DEPENDENCY_TABLES
+		.iter()
+		.map(|key| vec![*key])
+		.chain([vec!["workspace", "dependencies"]]);
+	for path in paths {
+		if let Some(table) = get_table_mut(&mut document, &path) {
+			set_dependency_versions(table, packages, version);
+		}
+	}
+	Ok(document.to_string())
+}
+
+/// Updates `package.version` and `workspace.package.version` of a
+/// `Cargo.toml` file.
+///
+/// Versions that are inherited from the workspace are left untouched.
+pub fn update_cargo_manifest(contents: &str, version: &str) -> Result<String> {
+	let mut document = contents.parse::<DocumentMut>()?;
+	let package = set_table_version(&mut document, &["package"], version);
+	let workspace =
+		set_table_version(&mut document, &["workspace", "package"], version);
+	if !package && !workspace {
+		return Err(Error::VersionFileError(String::from(
+			"No package version found in the Cargo manifest",
+		)));
+	}
+	Ok(document.to_string())
+}
+
+/// Updates the top-level `version` of a `package.json` file.
+pub fn update_package_json(contents: &str, version: &str) -> Result<String> {
+	serde_json::from_str::<serde_json::Value>(contents)?;
+	let span = top_level_json_string(contents, "version").ok_or_else(|| {
+		Error::VersionFileError(String::from(
+			"No version found in the package.json file",
+		))
+	})?;
+	let mut contents = contents.to_string();
+	contents.replace_range(span, version);
+	Ok(contents)
+}
+
+/// Returns the span of the string value (without the quotes) of the given
+/// key in the top-level object of a JSON document.
+///
+/// The document is expected to be valid JSON.
+fn top_level_json_string(contents: &str, key: &str) -> Option<Range<usize>> {
+	let bytes = contents.as_bytes();
+	let mut depth = 0;
+	let mut is_value = false;
+	let mut is_key_matched = false;
+	let mut index = 0;
+	while index < bytes.len() {
+		match bytes[index] {
+			b'"' => {
+				let start = index + 1;
+				let mut end = start;
+				while *bytes.get(end)? != b'"' {
+					end += if bytes[end] == b'\\' { 2 } else { 1 };
+				}
+				if depth == 1 {
+					if !is_value {
+						is_key_matched = &contents[start..end] == key;
+					} else if is_key_matched {
+						return Some(start..end);
+					}
+				}
+				index = end;
+			}
+			b':' if depth == 1 => is_value = true,
+			b',' if depth == 1 => is_value = false,
+			b'{' | b'[' => depth += 1,
+			b'}' | b']' => depth -= 1,
+			_ => {}
+		}
+		index += 1;
+	}
+	None
+}
+
+/// Updates `project.version` or `tool.poetry.version` of a `pyproject.toml`
+/// file.
+pub fn update_pyproject(contents: &str, version: &str) -> Result<String> {
+	let mut document = contents.parse::<DocumentMut>()?;
+	let project = set_table_version(&mut document, &["project"], version);
+	let poetry = set_table_version(&mut document, &["tool", "poetry"], version);
+	if !project && !poetry {
+		return Err(Error::VersionFileError(String::from(
+			"No version found in the pyproject.toml file",
+		)));
+	}
+	Ok(document.to_string())
+}
+
+/// Replaces the versions that are matched by the given pattern.
+///
+/// The pattern is a regex that contains the `{version}` placeholder exactly
+/// once. All the matches in the contents are updated.
+pub fn update_pattern(
+	contents: &str,
+	pattern: &str,
+	version: &str,
+) -> Result<String> {
+	if pattern.matches(VERSION_PLACEHOLDER).count() != 1 {
+		return Err(Error::VersionFileError(format!(
+			"Pattern must contain the {VERSION_PLACEHOLDER} placeholder once: \
+			 {pattern}"
+		)));
+	}
+	let regex = Regex::new(&pattern.replace(VERSION_PLACEHOLDER, VERSION_REGEX))?;
+	let spans = regex
+		.captures_iter(contents)
+		.filter_map(|captures| captures.name("version"))
+		.map(|v| v.range())
+		.collect::<Vec<_>>();
+	if spans.is_empty() {
+		return Err(Error::VersionFileError(format!(
+			"No version found with the pattern: {pattern}"
+		)));
+	}
+	let mut contents = contents.to_string();
+	for span in spans.into_iter().rev() {
+		contents.replace_range(span, version);
+	}
+	Ok(contents)
+}
+
+/// Returns the format of the version file.
+fn file_format(file: &VersionFile) -> Result<VersionFileFormat> {
+	if let Some(format) = file.format {
+		return Ok(format);
+	}
+	match file.path.file_name().and_then(|v| v.to_str()) {
+		Some("Cargo.toml") => Ok(VersionFileFormat::Cargo),
+		Some("package.json") => Ok(VersionFileFormat::PackageJson),
+		Some("pyproject.toml") => Ok(VersionFileFormat::Pyproject),
+		_ => Err(Error::VersionFileError(format!(
+			"Cannot infer the format of {:?}, set either `format` or `pattern`",
+			file.path
+		))),
+	}
+}
+
+/// Pending changes of the version files.
+#[derive(Default)]
+struct Changes(Vec<(PathBuf, String)>);
+
+impl Changes {
+	/// Returns the contents of the file, including the pending changes.
+	fn read(&self, path: &Path) -> Result<String> {
+		match self.0.iter().find(|(v, _)| v == path) {
+			Some((_, contents)) => Ok(contents.clone()),
+			None => Ok(fs::read_to_string(path)?),
+		}
+	}
+
+	/// Updates the file with the given function.
+	fn update(
+		&mut self,
+		path: &Path,
+		f: impl FnOnce(&str) -> Result<String>,
+	) -> Result<()> {
+		let contents = f(&self.read(path)?).map_err(|e| match e {
+			Error::VersionFileError(e) => {
+				Error::VersionFileError(format!("{e} ({path:?})"))
+			}
+			e => e,
+		})?;
+		match self.0.iter_mut().find(|(v, _)| v == path) {
+			Some((_, v)) => *v = contents,
+			None => self.0.push((path.to_path_buf(), contents)),
+		}
+		Ok(())
+	}
+}
+
+/// Updates the root manifest of a Cargo workspace along with its members.
+fn update_cargo_workspace(
+	changes: &mut Changes,
+	path: &Path,
+	version: &str,
+) -> Result<()> {
+	let root = path.parent().unwrap_or(Path::new(""));
+	let document = changes.read(path)?.parse::<DocumentMut>()?;
+	let members = document
+		.get("workspace")
+		.and_then(|v| v.get("members"))
+		.and_then(Item::as_array)
+		.map(|v| v.iter().filter_map(Value::as_str).collect::<Vec<&str>>())
+		.unwrap_or_default();
+	let mut manifests = vec![path.to_path_buf()];
+	for member in members {
+		let pattern = root.join(member).join("Cargo.toml");
+		for entry in glob::glob(&pattern.to_string_lossy())? {
+			let entry = entry.map_err(|e| Error::IoError(e.into_error()))?;
+			if !manifests.contains(&entry) {
+				manifests.push(entry);
+			}
+		}
+	}
+	let mut packages = Vec::new();
+	for manifest in &manifests {
+		let document = changes.read(manifest)?.parse::<DocumentMut>()?;
+		if let Some(name) = document
+			.get("package")
+			.and_then(|v| v.get("name"))
+			.and_then(Item::as_str)
+		{
+			packages.push(name.to_string());
+		}
+	}
+	for manifest in &manifests {
+		changes.update(manifest, |contents| {
+			let contents = match update_cargo_manifest(contents, version) {
+				Ok(contents) => contents,
+				Err(Error::VersionFileError(_)) => contents.to_string(),
+				Err(e) => return Err(e),
+			};
+			update_cargo_dependencies(&contents, &packages, version)
+		})?;
+	}
+	Ok(())
+}
+
+/// Writes the given version into the version files.
+///
+/// The new contents of all the files are computed before writing any of them
+/// and each file is replaced via a temporary file so that a failure does not
+/// leave the files partially updated. If replacing one of the files fails,
+/// the temporary files are removed and the already replaced files are
+/// restored.
+///
+/// Returns the paths of the updated files.
+pub fn write_version_files(
+	files: &[VersionFile],
+	version: &str,
+) -> Result<Vec<PathBuf>> {
+	let mut changes = Changes::default();
+	for file in files {
+		if let Some(pattern) = &file.pattern {
+			if file.format.is_some() {
+				return Err(Error::VersionFileError(format!(
+					"Only one of `format` or `pattern` can be set ({:?})",
+					file.path
+				)));
+			}
+			changes.update(&file.path, |v| update_pattern(v, pattern, version))?;
+			continue;
+		}
+		match file_format(file)? {
+			VersionFileFormat::Cargo => {
+				changes.update(&file.path, |v| update_cargo_manifest(v, version))?
+			}
+			VersionFileFormat::CargoWorkspace => {
+				update_cargo_workspace(&mut changes, &file.path, version)?
+			}
+			VersionFileFormat::PackageJson => {
+				changes.update(&file.path, |v| update_package_json(v, version))?
+			}
+			VersionFileFormat::Pyproject => {
+				changes.update(&file.path, |v| update_pyproject(v, version))?
+			}
+		}
+	}
+	let mut temp_files = Vec::new();
+	for (path, contents) in &changes.0 {
+		let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+		let temp_path = path.with_file_name(format!(".{file_name}.tmp"));
+		let original = fs::read(path)
+			.and_then(|original| fs::write(&temp_path, contents).map(|_| original));
+		match original {
+			Ok(original) => temp_files.push((temp_path, path.clone(), original)),
+			Err(e) => {
+				for (temp_path, _, _) in temp_files {
+					let _ = fs::remove_file(temp_path);
+				}
+				return Err(e.into());
+			}
+		}
+	}
+	for (i, (temp_path, path, _)) in temp_files.iter().enumerate() {
+		if let Err(e) = fs::rename(temp_path, path) {
+			for (temp_path, _, _) in &temp_files[i..] {
+				let _ = fs::remove_file(temp_path);
+			}
+			for (_, path, original) in &temp_files[..i] {
+				let _ = fs::write(path, original);
+			}
+			return Err(e.into());
+		}
+	}
+	Ok(temp_files.into_iter().map(|(_, path, _)| path).collect())
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use pretty_assertions::assert_eq;
+	use temp_dir::TempDir;
+
+	#[test]
+	fn update_cargo_version() -> Result<()> {
+		let contents = r#"[package]
+name = "abc"
+version = "0.1.0" # managed by release.sh
+
+[dependencies]
+regex = "1.11.1"
+"#;
+		assert_eq!(
+			r#"[package]
+name = "abc"
+version = "0.2.0" # managed by release.sh
+
+[dependencies]
+regex = "1.11.1"
+"#,
+			update_cargo_manifest(contents, "0.2.0")?
+		);
+		assert!(update_cargo_manifest(
+			"[package]\nname = \"abc\"\nversion.workspace = true\n",
+			"0.2.0"
+		)
+		.is_err());
+		Ok(())
+	}
+
+	#[test]
+	fn update_package_json_version() -> Result<()> {
+		let contents = r#"{
+  "name": "abc",
+  "version": "0.1.0",
+  "dependencies": {
+    "xyz": { "version": "0.1.0" }
+  }
+}
+"#;
+		assert_eq!(
+			r#"{
+  "name": "abc",
+  "version": "0.2.0",
+  "dependencies": {
+    "xyz": { "version": "0.1.0" }
+  }
+}
+"#,
+			update_package_json(contents, "0.2.0")?
+		);
+		assert_eq!(
+			r#"{"engines": {"node": {"version": "0.1.0"}}, "dep": ["version", "x"], "version": "0.2.0"}"#,
+			update_package_json(
+				r#"{"engines": {"node": {"version": "0.1.0"}}, "dep": ["version", "x"], "version": "0.1.0"}"#,
+				"0.2.0"
+			)?
+		);
+		assert_eq!(
+			r#"{ "name": "a \"version\": \"0.1.0\"", "version": "0.2.0" }"#,
+			update_package_json(
+				r#"{ "name": "a \"version\": \"0.1.0\"", "version": "0.1.0" }"#,
+				"0.2.0"
+			)?
+		);
+		assert!(update_package_json(r#"{ "name": "abc" }"#, "0.2.0").is_err());
+		assert!(update_package_json(
+			r#"{ "x": { "version": "0.1.0" }, "version": 1 }"#,
+			"0.2.0"
+		)
+		.is_err());
+		Ok(())
+	}
+
+	#[test]
+	fn update_pyproject_version() -> Result<()> {
+		assert_eq!(
+			"[project]\nname = \"abc\"\nversion = \"0.2.0\"\n",
+			update_pyproject(
+				"[project]\nname = \"abc\"\nversion = \"0.1.0\"\n",
+				"0.2.0"
+			)?
+		);
+		assert_eq!(
+			"[tool.poetry]\nversion = \"0.2.0\"\n",
+			update_pyproject("[tool.poetry]\nversion = '0.1.0'\n", "0.2.0")?
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn update_version_with_pattern() -> Result<()> {
+		assert_eq!(
+			"VERSION = \"0.2.0\"\nrequires = \"0.1.0\"\nVERSION = \"0.2.0\"\n",
+			update_pattern(
+				"VERSION = \"0.1.0\"\nrequires = \"0.1.0\"\nVERSION = \"0.1.1\"\n",
+				r#"VERSION = "{version}""#,
+				"0.2.0"
+			)?
+		);
+		assert!(
+			update_pattern("VERSION = 1", "VERSION = {version}", "0.2.0").is_err()
+		);
+		assert!(update_pattern("VERSION = 0.1.0", "VERSION", "0.2.0").is_err());
+		Ok(())
+	}
+
+	#[test]
+	fn write_cargo_workspace_versions() -> Result<()> {
+		let temp_dir =
+			TempDir::with_prefix("git-cliff-").expect("failed to create temp dir");
+		let root = temp_dir.path();
+		fs::create_dir_all(root.join("crates").join("abc"))?;
+		fs::create_dir_all(root.join("crates").join("xyz"))?;
+		fs::write(
+			root.join("Cargo.toml"),
+			"[workspace]\nmembers = [\"crates/*\"]\n\n[workspace.package]\nversion \
+			 = \"0.1.0\"\n",
+		)?;
+		fs::write(
+			root.join("crates").join("abc").join("Cargo.toml"),
+			"[package]\nname = \"abc\"\nversion.workspace = true\n",
+		)?;
+		fs::write(
+			root.join("crates").join("xyz").join("Cargo.toml"),
+			"[package]\nname = \"xyz\"\nversion = \"0.1.0\"\n\n[dependencies]\nabc \
+			 = { version = \"=0.1.0\", path = \"../abc\" }\nregex = \"1.11.1\"\n",
+		)?;
+		fs::write(root.join("README.md"), "Install abc@0.1.0\n")?;
+		let updated = write_version_files(
+			&[
+				VersionFile {
+					path:    root.join("Cargo.toml"),
+					format:  Some(VersionFileFormat::CargoWorkspace),
+					pattern: None,
+				},
+				VersionFile {
+					path:    root.join("README.md"),
+					format:  None,
+					pattern: Some(String::from("abc@{version}")),
+				},
+			],
+			"0.2.0",
+		)?;
+		assert_eq!(4, updated.len());
+		assert_eq!(
+			"[workspace]\nmembers = [\"crates/*\"]\n\n[workspace.package]\nversion \
+			 = \"0.2.0\"\n",
+			fs::read_to_string(root.join("Cargo.toml"))?
+		);
+		assert_eq!(
+			"[package]\nname = \"abc\"\nversion.workspace = true\n",
+			fs::read_to_string(root.join("crates").join("abc").join("Cargo.toml"))?
+		);
+		assert_eq!(
+			"[package]\nname = \"xyz\"\nversion = \"0.2.0\"\n\n[dependencies]\nabc \
+			 = { version = \"=0.2.0\", path = \"../abc\" }\nregex = \"1.11.1\"\n",
+			fs::read_to_string(root.join("crates").join("xyz").join("Cargo.toml"))?
+		);
+		assert_eq!(
+			"Install abc@0.2.0\n",
+			fs::read_to_string(root.join("README.md"))?
+		);
+
+		let result = write_version_files(
+			&[
+				VersionFile {
+					path:    root.join("README.md"),
+					format:  None,
+					pattern: Some(String::from("abc@{version}")),
+				},
+				VersionFile {
+					path:    root.join("missing.json"),
+					format:  Some(VersionFileFormat::PackageJson),
+					pattern: None,
+				},
+			],
+			"0.3.0",
+		);
+		assert!(result.is_err());
+		assert_eq!(
+			"Install abc@0.2.0\n",
+			fs::read_to_string(root.join("README.md"))?
+		);
+
+		// Both paths share the same temporary file, so the second rename fails.
+		let result = write_version_files(
+			&[
+				VersionFile {
+					path:    root.join("README.md"),
+					format:  None,
+					pattern: Some(String::from("abc@{version}")),
+				},
+				VersionFile {
+					path:    root.join("crates").join("..").join("README.md"),
+					format:  None,
+					pattern: Some(String::from("abc@{version}")),
+				},
+			],
+			"0.3.0",
+		);
+		assert!(result.is_err());
+		assert_eq!(
+			"Install abc@0.2.0\n",
+			fs::read_to_string(root.join("README.md"))?
+		);
+		assert!(!root.join(".README.md.tmp").exists());
+		Ok(())
+	}
+}
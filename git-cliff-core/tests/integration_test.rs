@@ -1,5 +1,6 @@
 use git_cliff_core::commit::{
 	Commit,
+	DiffStats,
 	Signature,
 };
 use git_cliff_core::config::{
@@ -43,6 +44,7 @@ fn generate_changelog() -> Result<()> {
 		render_always:  None,
 		postprocessors: None,
 		output:         None,
+		tag_message:    None,
 	};
 	let git_config = GitConfig {
 		conventional_commits:     Some(true),
@@ -55,74 +57,91 @@ fn generate_changelog() -> Result<()> {
 		}]),
 		commit_parsers:           Some(vec![
 			CommitParser {
-				sha:           Some(String::from("coffee")),
-				message:       None,
-				body:          None,
-				footer:        None,
-				group:         Some(String::from("I love coffee")),
+				sha: Some(String::from("coffee")),
+				message: None,
+				body: None,
+				footer: None,
+				group: Some(String::from("I love coffee")),
 				default_scope: None,
-				scope:         None,
-				skip:          None,
-				field:         None,
-				pattern:       None,
+				scope: None,
+				skip: None,
+				field: None,
+				pattern: None,
+				..Default::default()
 			},
 			CommitParser {
-				sha:           None,
-				message:       Regex::new("^feat").ok(),
-				body:          None,
-				footer:        None,
-				group:         Some(String::from("shiny features")),
+				sha: None,
+				message: Regex::new("^feat").ok(),
+				body: None,
+				footer: None,
+				group: Some(String::from("shiny features")),
 				default_scope: None,
-				scope:         None,
-				skip:          None,
-				field:         None,
-				pattern:       None,
+				scope: None,
+				skip: None,
+				field: None,
+				pattern: None,
+				..Default::default()
 			},
 			CommitParser {
-				sha:           None,
-				message:       Regex::new("^fix").ok(),
-				body:          None,
-				footer:        None,
-				group:         Some(String::from("fix bugs")),
+				sha: None,
+				message: Regex::new("^fix").ok(),
+				body: None,
+				footer: None,
+				group: Some(String::from("fix bugs")),
 				default_scope: None,
-				scope:         None,
-				skip:          None,
-				field:         None,
-				pattern:       None,
+				scope: None,
+				skip: None,
+				field: None,
+				pattern: None,
+				..Default::default()
 			},
 			CommitParser {
-				sha:           None,
-				message:       Regex::new("^test").ok(),
-				body:          None,
-				footer:        None,
-				group:         None,
+				sha: None,
+				message: Regex::new("^test").ok(),
+				body: None,
+				footer: None,
+				group: None,
 				default_scope: None,
-				scope:         Some(String::from("tests")),
-				skip:          None,
-				field:         None,
-				pattern:       None,
+				scope: Some(String::from("tests")),
+				skip: None,
+				field: None,
+				pattern: None,
+				..Default::default()
 			},
 			CommitParser {
-				sha:           None,
-				message:       None,
-				body:          None,
-				footer:        None,
-				group:         Some(String::from("docs")),
+				sha: None,
+				message: None,
+				body: None,
+				footer: None,
+				group: Some(String::from("docs")),
 				default_scope: None,
-				scope:         None,
-				skip:          None,
-				field:         Some(String::from("author.name")),
-				pattern:       Regex::new("John Doe").ok(),
+				scope: None,
+				skip: None,
+				field: Some(String::from("author.name")),
+				pattern: Regex::new("John Doe").ok(),
+				..Default::default()
 			},
 		]),
 		protect_breaking_commits: None,
 		filter_commits:           Some(true),
+		collapse_fixups:          None,
+		cancel_reverts:           None,
+		dedupe_cherry_picks:      None,
+		changelog_trailers:       None,
 		tag_pattern:              None,
+		release_branch_pattern:   None,
 		skip_tags:                None,
 		ignore_tags:              None,
 		count_tags:               None,
 		use_branch_tags:          None,
 		topo_order:               None,
+		fold_prereleases:         None,
+		tag_sort:                 None,
+		first_parent:             None,
+		since:                    None,
+		until:                    None,
+		date_field:               None,
+		release_period:           None,
 		sort_commits:             None,
 		link_parsers:             Some(vec![
 			LinkParser {
@@ -137,6 +156,7 @@ fn generate_changelog() -> Result<()> {
 			},
 		]),
 		limit_commits:            None,
+		mailmap:                  None,
 	};
 
 	let mut commit_with_author = Commit::new(
@@ -154,6 +174,9 @@ fn generate_changelog() -> Result<()> {
 		Release {
 			version:   Some(String::from("v2.0.0")),
 			message: None,
+            stats: DiffStats::default(),
+            breaking_changes: vec![],
+            prereleases: vec![],
             extra: None,
 			commits:   vec![
 
@@ -220,6 +243,9 @@ fn generate_changelog() -> Result<()> {
 		Release {
 			version:   Some(String::from("v1.0.0")),
 			message: None,
+            stats: DiffStats::default(),
+            breaking_changes: vec![],
+            prereleases: vec![],
             extra: None,
 			commits:   vec![
 				Commit::new(
@@ -49,7 +49,7 @@ const STYLES: Styles = Styles::styled()
 	.placeholder(AnsiColor::Green.on_default());
 
 /// Command-line arguments to parse.
-#[derive(Debug, Parser)]
+#[derive(Debug, Clone, Parser)]
 #[command(
     version,
     author = clap::crate_authors!("\n"),
@@ -91,7 +91,8 @@ pub struct Opt {
 	/// Increases the logging verbosity.
 	#[arg(short, long, action = ArgAction::Count, alias = "debug", help_heading = Some("FLAGS"))]
 	pub verbose:          u8,
-	/// Writes the default configuration file to cliff.toml
+	/// Writes the default configuration file to cliff.toml or the given
+	/// --config path
 	#[arg(
 	    short,
 	    long,
@@ -148,6 +149,12 @@ pub struct Opt {
 	/// Sets the regex for matching git tags.
 	#[arg(long, env = "GIT_CLIFF_TAG_PATTERN", value_name = "PATTERN")]
 	pub tag_pattern:      Option<Regex>,
+	/// Processes the commits made on or after the given date.
+	#[arg(long, env = "GIT_CLIFF_SINCE", value_name = "DATE")]
+	pub since:            Option<String>,
+	/// Processes the commits made on or before the given date.
+	#[arg(long, env = "GIT_CLIFF_UNTIL", value_name = "DATE")]
+	pub until:            Option<String>,
 	/// Sets custom commit messages to include in the changelog.
 	#[arg(
 		long,
@@ -220,6 +227,25 @@ pub struct Opt {
 	/// Prints bumped version for unreleased changes.
 	#[arg(long, help_heading = Some("FLAGS"))]
 	pub bumped_version:   bool,
+	/// Creates an annotated tag for the bumped version.
+	#[arg(
+		long,
+		requires = "bump",
+		conflicts_with = "bumped_version",
+		help_heading = Some("FLAGS")
+	)]
+	pub create_tag:       bool,
+	/// Signs the created tag with the configured GPG or SSH key.
+	#[arg(long, requires = "create_tag", help_heading = Some("FLAGS"))]
+	pub sign_tag:         bool,
+	/// Writes the bumped version into the configured version files.
+	#[arg(
+		long,
+		requires = "bump",
+		conflicts_with = "create_tag",
+		help_heading = Some("FLAGS")
+	)]
+	pub write_version:    bool,
 	/// Sets the template for the changelog body.
 	#[arg(
 		short,
@@ -244,12 +270,27 @@ pub struct Opt {
 	/// Include only the tags that belong to the current branch.
 	#[arg(long, help_heading = Some("FLAGS"))]
 	pub use_branch_tags:  bool,
+	/// Follows only the first parent of the merge commits.
+	#[arg(long, help_heading = Some("FLAGS"))]
+	pub first_parent:     bool,
 	/// Disables the external command execution.
 	#[arg(long, help_heading = Some("FLAGS"))]
 	pub no_exec:          bool,
 	/// Prints changelog context as JSON.
 	#[arg(short = 'x', long, help_heading = Some("FLAGS"))]
 	pub context:          bool,
+	/// Prints the JSON schema of the configuration file.
+	#[arg(long, help_heading = Some("FLAGS"))]
+	pub schema:           bool,
+	/// Fails on unknown keys in the configuration file.
+	#[arg(long, help_heading = Some("FLAGS"))]
+	pub strict_config:    bool,
+	/// Checks the configuration file for problems.
+	#[arg(long, help_heading = Some("FLAGS"))]
+	pub check_config:     bool,
+	/// Migrates the deprecated variables and fields in the configuration file.
+	#[arg(long, help_heading = Some("FLAGS"))]
+	pub migrate_config:   bool,
 	/// Generates changelog from a JSON context.
 	#[arg(
         long,
@@ -20,11 +20,17 @@ use args::{
 	Strip,
 };
 use clap::ValueEnum;
-use git_cliff_core::changelog::Changelog;
+use git_cliff_core::changelog::{
+	Changelog,
+	CommitCache,
+};
 use git_cliff_core::commit::Commit;
 use git_cliff_core::config::{
 	CommitParser,
 	Config,
+	ConfigFormat,
+	DateField,
+	TagSort,
 };
 use git_cliff_core::embed::{
 	BuiltinConfig,
@@ -34,20 +40,39 @@ use git_cliff_core::error::{
 	Error,
 	Result,
 };
-use git_cliff_core::release::Release;
+use git_cliff_core::lint;
+use git_cliff_core::migrate;
+use git_cliff_core::release::{
+	fold_prereleases,
+	parse_version,
+	Release,
+};
 use git_cliff_core::repo::Repository;
+use git_cliff_core::tag::Tag;
+use git_cliff_core::template::Template;
+use git_cliff_core::version;
 use git_cliff_core::{
+	CONFIG_FILES,
 	DEFAULT_CONFIG,
 	IGNORE_FILE,
 };
 use glob::Pattern;
+use regex::Regex;
+use similar::TextDiff;
+use std::collections::HashMap;
 use std::env;
 use std::fs::{
 	self,
 	File,
 };
-use std::io;
-use std::path::Path;
+use std::io::{
+	self,
+	Write,
+};
+use std::path::{
+	Path,
+	PathBuf,
+};
 use std::time::{
 	SystemTime,
 	UNIX_EPOCH,
@@ -78,52 +103,20 @@ fn check_new_version() {
 /// changelog.
 ///
 /// This function uses the configuration and arguments to process the given
-/// repository individually.
+/// repository individually. The releases are returned for each project, or
+/// only for the repository if there are no projects. The history is walked
+/// once for each commit range and the commits are assigned to the projects
+/// based on the files that they change.
 fn process_repository<'a>(
 	repository: &'static Repository,
 	config: &mut Config,
 	args: &Opt,
-) -> Result<Vec<Release<'a>>> {
-	let mut tags = repository.tags(
-		&config.git.tag_pattern,
-		args.topo_order,
-		args.use_branch_tags,
-	)?;
-	let skip_regex = config.git.skip_tags.as_ref();
-	let ignore_regex = config.git.ignore_tags.as_ref();
-	let count_tags = config.git.count_tags.as_ref();
-	tags.retain(|_, tag| {
-		let name = &tag.name;
-
-		// Keep skip tags to drop commits in the later stage.
-		let skip = skip_regex.is_some_and(|r| r.is_match(name));
-		if skip {
-			return true;
-		}
-
-		let count = count_tags.is_none_or(|r| {
-			let count_tag = r.is_match(name);
-			if count_tag {
-				trace!("Counting release: {}", name);
-			}
-			count_tag
-		});
-
-		let ignore = ignore_regex.is_some_and(|r| {
-			if r.as_str().trim().is_empty() {
-				return false;
-			}
-
-			let ignore_tag = r.is_match(name);
-			if ignore_tag {
-				trace!("Ignoring release: {}", name);
-			}
-			ignore_tag
-		});
-
-		count && !ignore
-	});
-
+) -> Result<Vec<Vec<Release<'a>>>> {
+	let tag_sort = if args.topo_order {
+		TagSort::Topological
+	} else {
+		config.git.tag_sort.unwrap_or_default()
+	};
 	if !config.remote.is_any_set() {
 		match repository.upstream_remote() {
 			Ok(remote) => {
@@ -159,209 +152,493 @@ fn process_repository<'a>(
 	log::trace!("Arguments: {:#?}", args);
 	log::trace!("Config: {:#?}", config);
 
-	// Parse commits.
-	let mut commit_range = args.range.clone();
-	if args.unreleased {
-		if let Some(last_tag) = tags.last().map(|(k, _)| k) {
-			commit_range = Some(format!("{last_tag}..HEAD"));
-		}
-	} else if args.latest || args.current {
-		if tags.len() < 2 {
-			let commits = repository.commits(None, None, None)?;
-			if let (Some(tag1), Some(tag2)) = (
-				commits.last().map(|c| c.id().to_string()),
-				tags.get_index(0).map(|(k, _)| k),
-			) {
-				if tags.len() == 1 {
-					commit_range = Some(tag2.to_owned());
-				} else {
-					commit_range = Some(format!("{tag1}..{tag2}"));
+	let config = &*config;
+	let projects = if config.projects.is_empty() {
+		vec![None]
+	} else {
+		config.projects.iter().map(Some).collect()
+	};
+	let tag_patterns = projects
+		.iter()
+		.map(|project| {
+			project
+				.and_then(|project| project.tag_pattern.as_ref())
+				.or(config.git.tag_pattern.as_ref())
+		})
+		.collect::<Vec<_>>();
+
+	// Get the tags once for each tag pattern.
+	let skip_regex = config.git.skip_tags.as_ref();
+	let ignore_regex = config.git.ignore_tags.as_ref();
+	let count_tags = config.git.count_tags.as_ref();
+	let mut fetched_patterns = Vec::new();
+	let mut tags_of_patterns = Vec::new();
+	for pattern in &tag_patterns {
+		let pattern_str = pattern.map(Regex::as_str);
+		if fetched_patterns.contains(&pattern_str) {
+			continue;
+		}
+		let mut tags = repository.tags(
+			&pattern.cloned(),
+			&config.git.release_branch_pattern,
+			tag_sort,
+			args.use_branch_tags,
+		)?;
+		tags.retain(|_, tag| {
+			let name = &tag.name;
+
+			// Keep skip tags to drop commits in the later stage.
+			let skip = skip_regex.is_some_and(|r| r.is_match(name));
+			if skip {
+				return true;
+			}
+
+			let count = count_tags.is_none_or(|r| {
+				let count_tag = r.is_match(name);
+				if count_tag {
+					trace!("Counting release: {}", name);
+				}
+				count_tag
+			});
+
+			let ignore = ignore_regex.is_some_and(|r| {
+				if r.as_str().trim().is_empty() {
+					return false;
 				}
+
+				let ignore_tag = r.is_match(name);
+				if ignore_tag {
+					trace!("Ignoring release: {}", name);
+				}
+				ignore_tag
+			});
+
+			count && !ignore
+		});
+		fetched_patterns.push(pattern_str);
+		tags_of_patterns.push(tags);
+	}
+
+	// Diffs are only calculated when they are needed since it is expensive for
+	// the commits that are not cached yet. The renamed files and the diff
+	// statistics are only calculated when they are used in the templates, the
+	// commit parsers or the context.
+	let match_paths = config
+		.git
+		.commit_parsers
+		.iter()
+		.flatten()
+		.any(|parser| parser.paths.is_some());
+	let trim = config.changelog.trim.unwrap_or(true);
+	let diff_fields = ["files", "stats"];
+	let mut list_files = args.context ||
+		config
+			.git
+			.commit_parsers
+			.iter()
+			.flatten()
+			.flat_map(|parser| [&parser.field, &parser.not_field])
+			.flatten()
+			.any(|field| {
+				diff_fields.contains(&field.split('.').next().unwrap_or_default())
+			});
+	for template in [
+		&config.changelog.header,
+		&config.changelog.body,
+		&config.changelog.footer,
+		&config.changelog.tag_message,
+	]
+	.into_iter()
+	.chain(
+		config
+			.projects
+			.iter()
+			.flat_map(|project| [&project.header, &project.body, &project.footer]),
+	)
+	.flatten()
+	{
+		if list_files {
+			break;
+		}
+		list_files = Template::new("template", template.to_string(), trim)?
+			.accesses_field(&diff_fields)?;
+	}
+
+	let date_field = config.git.date_field.unwrap_or_default();
+	let (since, until) = config.git.time_range()?;
+
+	// Commits of the walked ranges and the converted commits that are shared
+	// between the projects.
+	let mut walks = Vec::<(Option<String>, Vec<_>)>::new();
+	let mut converted = HashMap::<String, Commit<'a>>::new();
+	let mut project_releases = Vec::new();
+	for (project, pattern) in projects.into_iter().zip(&tag_patterns) {
+		let pattern_str = pattern.map(Regex::as_str);
+		let mut tags = fetched_patterns
+			.iter()
+			.position(|v| *v == pattern_str)
+			.map(|index| tags_of_patterns[index].clone())
+			.unwrap_or_default();
+
+		// Parse commits.
+		let mut commit_range = args.range.clone();
+		if args.unreleased {
+			if let Some(last_tag) = tags.last().map(|(k, _)| k) {
+				commit_range = Some(format!("{last_tag}..HEAD"));
 			}
-		} else {
-			let mut tag_index = tags.len() - 2;
-			if args.current {
-				if let Some(current_tag_index) =
-					repository.current_tag().as_ref().and_then(|tag| {
-						tags.iter()
-							.enumerate()
-							.find(|(_, (_, v))| v.name == tag.name)
-							.map(|(i, _)| i)
-					}) {
-					match current_tag_index.checked_sub(1) {
-						Some(i) => tag_index = i,
-						None => {
-							return Err(Error::ChangelogError(String::from(
-								"No suitable tags found. Maybe run with \
-								 '--topo-order'?",
-							)));
+		} else if args.latest || args.current {
+			if tags.len() < 2 {
+				let commits =
+					repository.commits(None, None, None, args.first_parent, None)?;
+				if let (Some(tag1), Some(tag2)) = (
+					commits.last().map(|c| c.id().to_string()),
+					tags.get_index(0).map(|(k, _)| k),
+				) {
+					if tags.len() == 1 {
+						commit_range = Some(tag2.to_owned());
+					} else {
+						commit_range = Some(format!("{tag1}..{tag2}"));
+					}
+				}
+			} else {
+				let mut tag_index = tags.len() - 2;
+				if args.current {
+					if let Some(current_tag_index) =
+						repository.current_tag().as_ref().and_then(|tag| {
+							tags.iter()
+								.enumerate()
+								.find(|(_, (_, v))| v.name == tag.name)
+								.map(|(i, _)| i)
+						}) {
+						match current_tag_index.checked_sub(1) {
+							Some(i) => tag_index = i,
+							None => {
+								return Err(Error::ChangelogError(String::from(
+									"No suitable tags found. Maybe run with \
+									 '--topo-order'?",
+								)));
+							}
 						}
+					} else {
+						return Err(Error::ChangelogError(String::from(
+							"No tag exists for the current commit",
+						)));
 					}
-				} else {
-					return Err(Error::ChangelogError(String::from(
-						"No tag exists for the current commit",
-					)));
+				}
+				// Start from the previous final release if the pre-releases are
+				// folded into it.
+				let fold_prereleases = config.git.fold_prereleases.unwrap_or(false);
+				let start_index = (0..=tag_index).rev().find(|i| {
+					!fold_prereleases ||
+						tags.get_index(*i).is_some_and(|(_, tag)| {
+							parse_version(&tag.name)
+								.map_or(true, |(_, version)| version.pre.is_empty())
+						})
+				});
+				match (
+					start_index.and_then(|i| tags.get_index(i)).map(|(k, _)| k),
+					tags.get_index(tag_index + 1).map(|(k, _)| k),
+				) {
+					(Some(tag1), Some(tag2)) => {
+						commit_range = Some(format!("{tag1}..{tag2}"));
+					}
+					(None, Some(tag2)) => commit_range = Some(tag2.to_owned()),
+					_ => {}
 				}
 			}
-			if let (Some(tag1), Some(tag2)) = (
-				tags.get_index(tag_index).map(|(k, _)| k),
-				tags.get_index(tag_index + 1).map(|(k, _)| k),
-			) {
-				commit_range = Some(format!("{tag1}..{tag2}"));
+		}
+
+		let to_patterns = |paths: &Vec<String>| {
+			paths
+				.iter()
+				.map(|path| Pattern::new(path))
+				.collect::<std::result::Result<Vec<_>, _>>()
+		};
+		let mut include_path =
+			match project.and_then(|project| project.include_paths.as_ref()) {
+				Some(paths) => Some(to_patterns(paths)?),
+				None => args.include_path.clone(),
+			};
+		let exclude_path =
+			match project.and_then(|project| project.exclude_paths.as_ref()) {
+				Some(paths) => Some(to_patterns(paths)?),
+				None => args.exclude_path.clone(),
+			};
+
+		// Include only the current directory if not running from the root repository
+		if let Some(mut path_diff) =
+			pathdiff::diff_paths(env::current_dir()?, repository.path())
+		{
+			if include_path.is_none() && path_diff != Path::new("") {
+				info!(
+					"Including changes from the current directory: {:?}",
+					path_diff.display()
+				);
+				path_diff.extend(["**", "*"]);
+				include_path =
+					Some(vec![Pattern::new(path_diff.to_string_lossy().as_ref())?]);
 			}
 		}
-	}
 
-	// Include only the current directory if not running from the root repository
-	let mut include_path = args.include_path.clone();
-	if let Some(mut path_diff) =
-		pathdiff::diff_paths(env::current_dir()?, repository.path())
-	{
-		if include_path.is_none() && path_diff != Path::new("") {
-			info!(
-				"Including changes from the current directory: {:?}",
-				path_diff.display()
-			);
-			path_diff.extend(["**", "*"]);
-			include_path =
-				Some(vec![Pattern::new(path_diff.to_string_lossy().as_ref())?]);
-		}
-	}
-
-	let mut commits = repository.commits(
-		commit_range.as_deref(),
-		include_path,
-		args.exclude_path.clone(),
-	)?;
-	if let Some(commit_limit_value) = config.git.limit_commits {
-		commits.truncate(commit_limit_value);
-	}
-
-	// Update tags.
-	let mut releases = vec![Release::default()];
-	let mut tag_timestamp = None;
-	if let Some(ref tag) = args.tag {
-		if let Some(commit_id) = commits.first().map(|c| c.id().to_string()) {
-			match tags.get(&commit_id) {
-				Some(tag) => {
-					warn!("There is already a tag ({}) for {}", tag.name, commit_id);
-					tag_timestamp = Some(commits[0].time().seconds());
-				}
+		// Walk the history once for each range. The commits outside of the time
+		// range are dropped before checking their changed files. The walk is
+		// only pruned by the committer date since the author dates of the
+		// ancestors can be later.
+		let walk_index =
+			match walks.iter().position(|(range, _)| *range == commit_range) {
+				Some(index) => index,
 				None => {
-					tags.insert(commit_id, repository.resolve_tag(tag));
+					let mut commits = repository.commits(
+						commit_range.as_deref(),
+						None,
+						None,
+						args.first_parent,
+						since.filter(|_| date_field == DateField::Committer),
+					)?;
+					if since.is_some() || until.is_some() {
+						commits.retain(|commit| {
+							let time = repository.commit_time(commit, date_field);
+							since.is_none_or(|since| time >= since) &&
+								until.is_none_or(|until| time <= until)
+						});
+					}
+					walks.push((commit_range.clone(), commits));
+					walks.len() - 1
 				}
+			};
+		let mut commits = repository.filter_commits_by_path(
+			&walks[walk_index].1,
+			include_path,
+			exclude_path,
+		);
+		if let Some(commit_limit_value) = config.git.limit_commits {
+			commits.truncate(commit_limit_value);
+		}
+
+		// Split the releases by calendar periods instead of tags.
+		if let Some(period) = config.git.release_period {
+			commits.sort_by_key(|commit| {
+				std::cmp::Reverse(repository.commit_time(commit, date_field))
+			});
+			tags.clear();
+			let mut current_period: Option<(String, String)> = None;
+			for commit in commits.iter().rev() {
+				let name =
+					period.name(repository.commit_time(commit, date_field))?;
+				if let Some((commit_id, previous_name)) = current_period.take() {
+					if previous_name != name {
+						tags.insert(commit_id, Tag {
+							name:    previous_name,
+							message: None,
+						});
+					}
+				}
+				current_period = Some((commit.id().to_string(), name));
 			}
-		} else {
-			releases[0].version = Some(tag.to_string());
-			releases[0].timestamp = SystemTime::now()
-				.duration_since(UNIX_EPOCH)?
-				.as_secs()
-				.try_into()?;
-		}
-	}
-
-	// Process releases.
-	let mut previous_release = Release::default();
-	let mut first_processed_tag = None;
-	for git_commit in commits.iter().rev() {
-		let release = releases.last_mut().unwrap();
-		let commit = Commit::from(git_commit);
-		let commit_id = commit.id.to_string();
-		release.commits.push(commit);
-		release.repository = Some(repository.path().to_string_lossy().into_owned());
-		if let Some(tag) = tags.get(&commit_id) {
-			release.version = Some(tag.name.to_string());
-			release.message.clone_from(&tag.message);
-			release.commit_id = Some(commit_id);
-			release.timestamp = if args.tag.as_deref() == Some(tag.name.as_str()) {
-				match tag_timestamp {
-					Some(timestamp) => timestamp,
-					None => SystemTime::now()
-						.duration_since(UNIX_EPOCH)?
-						.as_secs()
-						.try_into()?,
+			if let Some((commit_id, name)) = current_period {
+				tags.insert(commit_id, Tag {
+					name,
+					message: None,
+				});
+			}
+		}
+
+		// Update tags.
+		let mut releases = vec![Release::default()];
+		let mut tag_timestamp = None;
+		if let Some(ref tag) = args.tag {
+			if let Some(commit_id) = commits.first().map(|c| c.id().to_string()) {
+				match tags.get(&commit_id) {
+					Some(tag) => {
+						warn!(
+							"There is already a tag ({}) for {}",
+							tag.name, commit_id
+						);
+						tag_timestamp = Some(commits[0].time().seconds());
+					}
+					None => {
+						tags.insert(commit_id, repository.resolve_tag(tag));
+					}
 				}
 			} else {
-				git_commit.time().seconds()
-			};
-			if first_processed_tag.is_none() {
-				first_processed_tag = Some(tag);
+				releases[0].version = Some(tag.to_string());
+				releases[0].timestamp = SystemTime::now()
+					.duration_since(UNIX_EPOCH)?
+					.as_secs()
+					.try_into()?;
+			}
+		}
+
+		// Process releases.
+		let mut previous_release = Release::default();
+		let mut first_processed_tag = None;
+		for git_commit in commits.iter().rev() {
+			let release = releases.last_mut().unwrap();
+			let commit_id = git_commit.id().to_string();
+			let commit = converted.entry(commit_id.clone()).or_insert_with(|| {
+				let mut commit = Commit::from(git_commit);
+				(commit.author, commit.committer) =
+					repository.commit_signatures(git_commit);
+				if args.first_parent {
+					if let Some(message) =
+						repository.merge_commit_message(git_commit)
+					{
+						commit.message = message;
+					}
+				}
+				if list_files || match_paths {
+					commit.files = repository.commit_files(git_commit, list_files);
+				}
+				if config.git.dedupe_cherry_picks.unwrap_or(false) {
+					commit.patch_id = repository.commit_patch_id(git_commit);
+				}
+				commit
+			});
+			release.commits.push(commit.clone());
+			release.repository =
+				Some(repository.path().to_string_lossy().into_owned());
+			if let Some(tag) = tags.get(&commit_id) {
+				release.version = Some(tag.name.to_string());
+				release.message.clone_from(&tag.message);
+				release.commit_id = Some(commit_id);
+				release.timestamp = if args.tag.as_deref() == Some(tag.name.as_str())
+				{
+					match tag_timestamp {
+						Some(timestamp) => timestamp,
+						None => SystemTime::now()
+							.duration_since(UNIX_EPOCH)?
+							.as_secs()
+							.try_into()?,
+					}
+				} else {
+					git_commit.time().seconds()
+				};
+				if first_processed_tag.is_none() {
+					first_processed_tag = Some(tag);
+				}
+				previous_release.previous = None;
+				release.previous = Some(Box::new(previous_release));
+				previous_release = release.clone();
+				releases.push(Release::default());
 			}
-			previous_release.previous = None;
-			release.previous = Some(Box::new(previous_release));
-			previous_release = release.clone();
-			releases.push(Release::default());
 		}
-	}
 
-	debug_assert!(!releases.is_empty());
+		debug_assert!(!releases.is_empty());
 
-	if releases.len() > 1 {
-		previous_release.previous = None;
-		releases.last_mut().unwrap().previous = Some(Box::new(previous_release));
-	}
+		if releases.len() > 1 {
+			previous_release.previous = None;
+			releases.last_mut().unwrap().previous = Some(Box::new(previous_release));
+		}
 
-	if args.sort == Sort::Newest {
-		for release in &mut releases {
-			release.commits.reverse();
+		// Order the releases by their versions (e.g. for the backport releases).
+		if tag_sort == TagSort::Semver && releases.len() > 2 {
+			let unreleased = releases.pop();
+			releases.sort_by_key(|release| {
+				release
+					.commit_id
+					.as_ref()
+					.and_then(|id| tags.get_index_of(id))
+			});
+			releases.extend(unreleased);
+			let mut previous_release = Release::default();
+			for release in &mut releases {
+				previous_release.previous = None;
+				release.previous = Some(Box::new(previous_release));
+				previous_release = release.clone();
+			}
+			first_processed_tag =
+				releases[0].commit_id.as_ref().and_then(|id| tags.get(id));
 		}
-	}
 
-	// Add custom commit messages to the latest release.
-	if let Some(custom_commits) = &args.with_commit {
-		releases
-			.last_mut()
-			.unwrap()
-			.commits
-			.extend(custom_commits.iter().cloned().map(Commit::from));
-	}
+		if config.git.fold_prereleases.unwrap_or(false) {
+			releases = fold_prereleases(releases);
+		}
 
-	// Set the previous release if the first release does not have one set.
-	if releases[0]
-		.previous
-		.as_ref()
-		.and_then(|p| p.version.as_ref())
-		.is_none()
-	{
-		// Get the previous tag of the first processed tag in the release loop.
-		let first_tag = first_processed_tag
-			.map(|tag| {
-				tags.iter()
-					.enumerate()
-					.find(|(_, (_, v))| v.name == tag.name)
-					.and_then(|(i, _)| i.checked_sub(1))
-					.and_then(|i| tags.get_index(i))
-			})
-			.or_else(|| Some(tags.last()))
-			.flatten();
-
-		// Set the previous release if the first tag is found.
-		if let Some((commit_id, tag)) = first_tag {
-			let previous_release = Release {
-				commit_id: Some(commit_id.to_string()),
-				version: Some(tag.name.clone()),
-				timestamp: repository
-					.find_commit(commit_id)
-					.map(|v| v.time().seconds())
-					.unwrap_or_default(),
-				..Default::default()
-			};
-			releases[0].previous = Some(Box::new(previous_release));
+		if args.sort == Sort::Newest {
+			for release in &mut releases {
+				release.commits.reverse();
+				for prerelease in &mut release.prereleases {
+					prerelease.commits.reverse();
+				}
+			}
+		}
+
+		// Add custom commit messages to the latest release.
+		if let Some(custom_commits) = &args.with_commit {
+			releases
+				.last_mut()
+				.unwrap()
+				.commits
+				.extend(custom_commits.iter().cloned().map(Commit::from));
 		}
-	}
 
-	// Set custom message for the latest release.
-	if let Some(message) = &args.with_tag_message {
-		if let Some(latest_release) = releases
-			.iter_mut()
-			.filter(|release| !release.commits.is_empty())
-			.last()
+		// Set the previous release if the first release does not have one set.
+		if releases[0]
+			.previous
+			.as_ref()
+			.and_then(|p| p.version.as_ref())
+			.is_none()
 		{
-			latest_release.message = Some(message.to_owned());
+			// Get the previous tag of the first processed tag in the release loop.
+			let first_tag = first_processed_tag
+				.map(|tag| {
+					tags.iter()
+						.enumerate()
+						.find(|(_, (_, v))| v.name == tag.name)
+						.and_then(|(i, _)| i.checked_sub(1))
+						.and_then(|i| tags.get_index(i))
+				})
+				.or_else(|| Some(tags.last()))
+				.flatten();
+
+			// Set the previous release if the first tag is found.
+			if let Some((commit_id, tag)) = first_tag {
+				let previous_release = Release {
+					commit_id: Some(commit_id.to_string()),
+					version: Some(tag.name.clone()),
+					timestamp: repository
+						.find_commit(commit_id)
+						.map(|v| v.time().seconds())
+						.unwrap_or_default(),
+					..Default::default()
+				};
+				releases[0].previous = Some(Box::new(previous_release));
+			}
+		}
+
+		// Set custom message for the latest release.
+		if let Some(message) = &args.with_tag_message {
+			if let Some(latest_release) = releases
+				.iter_mut()
+				.filter(|release| !release.commits.is_empty())
+				.last()
+			{
+				latest_release.message = Some(message.to_owned());
+			}
 		}
+
+		project_releases.push(releases);
 	}
 
-	Ok(releases)
+	Ok(project_releases)
+}
+
+/// Finds the first configuration file that exists in the given directory.
+fn find_config_in(dir: &Path) -> Option<PathBuf> {
+	CONFIG_FILES
+		.iter()
+		.map(|name| dir.join(name))
+		.find(|path| path.is_file())
+}
+
+/// Finds the configuration file in the current directory or its parents.
+fn find_config_in_parents() -> Result<Option<PathBuf>> {
+	Ok(env::current_dir()?.ancestors().find_map(find_config_in))
+}
+
+/// Returns the directory of the given configuration file.
+fn config_dir(path: &Path) -> PathBuf {
+	path.parent().map(Path::to_path_buf).unwrap_or_default()
 }
 
 /// Runs `git-cliff`.
@@ -376,12 +653,22 @@ pub fn run(mut args: Opt) -> Result<()> {
 			Some(ref name) => BuiltinConfig::get_config(name.to_string())?,
 			None => EmbeddedConfig::get_config()?,
 		};
+		let (path, format) = match ConfigFormat::from_path(&args.config) {
+			Some(format) => (args.config.clone(), format),
+			None => (PathBuf::from(DEFAULT_CONFIG), ConfigFormat::Toml),
+		};
 		info!(
 			"Saving the configuration file{} to {:?}",
 			init_config.map(|v| format!(" ({v})")).unwrap_or_default(),
-			DEFAULT_CONFIG
+			path
 		);
-		fs::write(DEFAULT_CONFIG, contents)?;
+		fs::write(path, format.convert(&contents)?)?;
+		return Ok(());
+	}
+
+	// Print the JSON schema of the configuration file if schema flag is given.
+	if args.schema {
+		println!("{}", Config::json_schema()?);
 		return Ok(());
 	}
 
@@ -407,37 +694,106 @@ pub fn run(mut args: Opt) -> Result<()> {
 
 	// Set path for the configuration file.
 	let mut path = args.config.clone();
+	if !path.exists() && path.file_name() == Some(DEFAULT_CONFIG.as_ref()) {
+		if let Some(config_path) = find_config_in(&config_dir(&path)) {
+			path = config_path;
+		}
+	}
 	if !path.exists() {
-		if let Some(config_path) = dirs::config_dir()
-			.map(|dir| dir.join(env!("CARGO_PKG_NAME")).join(DEFAULT_CONFIG))
-		{
+		if let Some(config_path) = dirs::config_dir().map(|dir| {
+			let dir = dir.join(env!("CARGO_PKG_NAME"));
+			find_config_in(&dir).unwrap_or_else(|| dir.join(DEFAULT_CONFIG))
+		}) {
 			path = config_path;
 		}
 	}
 
+	// Check the configuration file if check-config flag is given.
+	if args.check_config {
+		let (contents, base_dir) = if let Ok((_, ref name)) = builtin_config {
+			(
+				BuiltinConfig::get_config(name.to_string())?,
+				env::current_dir()?,
+			)
+		} else if path.exists() {
+			(Config::read_contents(&path)?, config_dir(&path))
+		} else if let Some(contents) = Config::read_from_manifest()? {
+			(contents, env::current_dir()?)
+		} else if let Some(discovered_path) = find_config_in_parents()? {
+			(
+				Config::read_contents(&discovered_path)?,
+				config_dir(&discovered_path),
+			)
+		} else {
+			(EmbeddedConfig::get_config()?, env::current_dir()?)
+		};
+		let issues = lint::check(&contents, &base_dir)?;
+		for issue in &issues {
+			error!("{issue}");
+		}
+		if !issues.is_empty() {
+			return Err(Error::ConfigCheckError(issues.len()));
+		}
+		info!("No problems found in the configuration.");
+		return Ok(());
+	}
+
+	// Migrate the configuration file if migrate-config flag is given.
+	if args.migrate_config {
+		let path = if path.exists() {
+			path
+		} else {
+			find_config_in_parents()?.ok_or_else(|| {
+				Error::ArgumentError(format!("{:?} is not found", args.config))
+			})?
+		};
+		if ConfigFormat::from_path(&path) != Some(ConfigFormat::Toml) {
+			return Err(Error::ArgumentError(String::from(
+				"only TOML configuration files can be migrated",
+			)));
+		}
+		let contents = fs::read_to_string(&path)?;
+		let migrated = migrate::migrate(&contents)?;
+		if contents == migrated {
+			info!("{path:?} is already up to date.");
+			return Ok(());
+		}
+		let name = path.to_string_lossy();
+		print!(
+			"{}",
+			TextDiff::from_lines(&contents, &migrated)
+				.unified_diff()
+				.header(&name, &name)
+		);
+		fs::write(&path, migrated)?;
+		info!("Migrated the configuration file {path:?}");
+		return Ok(());
+	}
+
 	// Parse the configuration file.
 	// Load the default configuration if necessary.
+	let parse_config = if args.strict_config {
+		Config::parse_strict
+	} else {
+		Config::parse
+	};
 	let mut config = if let Ok((config, name)) = builtin_config {
 		info!("Using built-in configuration file: {name}");
 		config
 	} else if path.exists() {
-		Config::parse(&path)?
+		parse_config(&path)?
 	} else if let Some(contents) = Config::read_from_manifest()? {
-		Config::parse_from_str(&contents)?
-	} else if let Some(discovered_path) =
-		env::current_dir()?.ancestors().find_map(|dir| {
-			let path = dir.join(DEFAULT_CONFIG);
-			if path.is_file() {
-				Some(path)
-			} else {
-				None
-			}
-		}) {
+		if args.strict_config {
+			Config::parse_from_str_strict(&contents)?
+		} else {
+			Config::parse_from_str(&contents)?
+		}
+	} else if let Some(discovered_path) = find_config_in_parents()? {
 		info!(
 			"Using configuration from parent directory: {}",
 			discovered_path.display()
 		);
-		Config::parse(&discovered_path)?
+		parse_config(&discovered_path)?
 	} else {
 		if !args.context {
 			warn!(
@@ -505,6 +861,12 @@ pub fn run(mut args: Opt) -> Result<()> {
 		}
 	}
 
+	if !args.first_parent {
+		if let Some(first_parent) = config.git.first_parent {
+			args.first_parent = first_parent;
+		}
+	}
+
 	if args.github_token.is_some() {
 		config.remote.github.token.clone_from(&args.github_token);
 	}
@@ -557,6 +919,12 @@ pub fn run(mut args: Opt) -> Result<()> {
 	if args.tag_pattern.is_some() {
 		config.git.tag_pattern.clone_from(&args.tag_pattern);
 	}
+	if args.since.is_some() {
+		config.git.since.clone_from(&args.since);
+	}
+	if args.until.is_some() {
+		config.git.until.clone_from(&args.until);
+	}
 	if args.tag.is_some() {
 		config.bump.initial_tag.clone_from(&args.tag);
 	}
@@ -573,7 +941,7 @@ pub fn run(mut args: Opt) -> Result<()> {
 	}
 
 	// Generate changelog from context.
-	let mut changelog: Changelog = if let Some(context_path) = args.from_context {
+	if let Some(context_path) = &args.from_context {
 		let mut input: Box<dyn io::Read> = if context_path == Path::new("-") {
 			Box::new(io::stdin())
 		} else {
@@ -581,51 +949,211 @@ pub fn run(mut args: Opt) -> Result<()> {
 		};
 		let mut changelog = Changelog::from_context(&mut input, &config)?;
 		changelog.add_remote_context()?;
-		changelog
-	} else {
-		// Process the repositories.
-		let repositories =
-			args.repository.clone().unwrap_or(vec![env::current_dir()?]);
-		let mut releases = Vec::<Release>::new();
-		for repository in repositories {
-			// Skip commits
-			let mut skip_list = Vec::new();
-			let ignore_file = repository.join(IGNORE_FILE);
-			if ignore_file.exists() {
-				let contents = fs::read_to_string(ignore_file)?;
-				let commits = contents
-					.lines()
-					.filter(|v| !(v.starts_with('#') || v.trim().is_empty()))
-					.map(|v| String::from(v.trim()))
-					.collect::<Vec<String>>();
-				skip_list.extend(commits);
+		return write_changelog(changelog, &config, &args, output.as_ref(), None);
+	}
+
+	// Open the repositories.
+	let repositories = args
+		.repository
+		.clone()
+		.unwrap_or(vec![env::current_dir()?])
+		.into_iter()
+		.map(|path| {
+			let mut repository = Repository::init(path.clone())?;
+			if let Some(mailmap) = &config.git.mailmap {
+				repository.add_mailmap(mailmap)?;
 			}
-			if let Some(ref skip_commit) = args.skip_commit {
-				skip_list.extend(skip_commit.clone());
+			Ok((path, &*Box::leak(Box::new(repository))))
+		})
+		.collect::<Result<Vec<_>>>()?;
+
+	// The tag is created in the first repository.
+	let repository = repositories.first().map(|(_, repository)| *repository);
+	if config.projects.is_empty() {
+		let releases = process_repositories(&repositories, &mut config, &args)?
+			.into_iter()
+			.next()
+			.unwrap_or_default();
+		let changelog = Changelog::new(releases, &config)?;
+		return write_changelog(
+			changelog,
+			&config,
+			&args,
+			output.as_ref(),
+			repository,
+		);
+	}
+
+	// Generate a changelog for each project.
+	if args.prepend.is_some() {
+		return Err(Error::ArgumentError(String::from(
+			"'-p' cannot be used with projects",
+		)));
+	}
+	if args.create_tag {
+		return Err(Error::ArgumentError(String::from(
+			"'--create-tag' cannot be used with projects",
+		)));
+	}
+	if args.write_version {
+		return Err(Error::ArgumentError(String::from(
+			"'--write-version' cannot be used with projects",
+		)));
+	}
+	if output.is_some() {
+		return Err(Error::ArgumentError(String::from(
+			"'-o' and 'changelog.output' cannot be used with projects, set the \
+			 output of each project instead",
+		)));
+	}
+	let releases = process_repositories(&repositories, &mut config, &args)?;
+	let project_configs = config
+		.projects
+		.iter()
+		.map(|project| {
+			let mut project_config = config.clone();
+			project.apply(&mut project_config);
+			project_config
+		})
+		.collect::<Vec<Config>>();
+	let commit_cache = CommitCache::default();
+	let mut print_separator = false;
+	for ((project, project_config), releases) in
+		config.projects.iter().zip(&project_configs).zip(releases)
+	{
+		info!("Generating the changelog of project: {}", project.name);
+		let mut changelog = Changelog::new_with_cache(
+			releases,
+			project_config,
+			Some(&commit_cache),
+		)?;
+		changelog.add_context("project", &project.name)?;
+		// Separate the changelogs that are printed to the standard output.
+		if project
+			.output
+			.as_ref()
+			.is_none_or(|path| path == Path::new("-"))
+		{
+			if print_separator {
+				println!();
 			}
-			if let Some(commit_parsers) = config.git.commit_parsers.as_mut() {
-				for sha1 in skip_list {
-					commit_parsers.insert(0, CommitParser {
-						sha: Some(sha1.to_string()),
-						skip: Some(true),
-						..Default::default()
-					});
-				}
+			print_separator = true;
+		}
+		write_changelog(
+			changelog,
+			project_config,
+			&args,
+			project.output.as_ref(),
+			repository,
+		)?;
+	}
+
+	Ok(())
+}
+
+/// Processes the given repositories and returns the releases of each
+/// project.
+///
+/// The commits listed in the ignore file of the repositories and via
+/// `--skip-commit` are skipped.
+fn process_repositories<'a>(
+	repositories: &[(PathBuf, &'static Repository)],
+	config: &mut Config,
+	args: &Opt,
+) -> Result<Vec<Vec<Release<'a>>>> {
+	let mut releases = Vec::<Vec<Release>>::new();
+	for (path, repository) in repositories {
+		// Skip commits
+		let mut skip_list = Vec::new();
+		let ignore_file = path.join(IGNORE_FILE);
+		if ignore_file.exists() {
+			let contents = fs::read_to_string(ignore_file)?;
+			let commits = contents
+				.lines()
+				.filter(|v| !(v.starts_with('#') || v.trim().is_empty()))
+				.map(|v| String::from(v.trim()))
+				.collect::<Vec<String>>();
+			skip_list.extend(commits);
+		}
+		if let Some(ref skip_commit) = args.skip_commit {
+			skip_list.extend(skip_commit.clone());
+		}
+		if let Some(commit_parsers) = config.git.commit_parsers.as_mut() {
+			for sha1 in skip_list {
+				commit_parsers.insert(0, CommitParser {
+					sha: Some(sha1.to_string()),
+					skip: Some(true),
+					..Default::default()
+				});
+			}
+		}
+
+		// Process the repository.
+		for (index, project_releases) in
+			process_repository(repository, config, args)?
+				.into_iter()
+				.enumerate()
+		{
+			match releases.get_mut(index) {
+				Some(releases) => releases.extend(project_releases),
+				None => releases.push(project_releases),
 			}
+		}
+	}
+	Ok(releases)
+}
 
-			// Process the repository.
-			let repository = Repository::init(repository)?;
-			releases.extend(process_repository(
-				Box::leak(Box::new(repository)),
-				&mut config,
-				&args,
-			)?);
+/// Writes the changelog to the given output.
+///
+/// Writes to the standard output if no output is given. The bumped version is
+/// written into the version files if `--write-version` is set and its tag is
+/// created in the given repository if `--create-tag` is set.
+fn write_changelog(
+	mut changelog: Changelog,
+	config: &Config,
+	args: &Opt,
+	output: Option<&PathBuf>,
+	repository: Option<&Repository>,
+) -> Result<()> {
+	// Bump the version.
+	let next_version = if args.bump.is_some() || args.bumped_version {
+		if let Some(next_version) = changelog.bump_version()? {
+			Some(next_version)
+		} else if let Some(last_version) =
+			changelog.releases.first().cloned().and_then(|v| v.version)
+		{
+			warn!("There is nothing to bump.");
+			Some(last_version)
+		} else if changelog.releases.is_empty() {
+			Some(config.bump.get_initial_tag())
+		} else {
+			return Ok(());
 		}
-		Changelog::new(releases, &config)?
+	} else {
+		None
 	};
 
+	// Check the tag before writing anything.
+	let mut tag = None;
+	if args.create_tag {
+		let repository = repository.ok_or_else(|| {
+			Error::ArgumentError(String::from(
+				"'--create-tag' requires a repository",
+			))
+		})?;
+		if let Some(next_version) = &next_version {
+			if repository.tag_exists(next_version) {
+				return Err(Error::RepoError(format!(
+					"Tag already exists: {next_version}"
+				)));
+			}
+			let message = changelog.generate_tag_message()?;
+			tag = Some((repository, next_version.clone(), message));
+		}
+	}
+
 	// Print the result.
-	let mut out: Box<dyn io::Write> = if let Some(path) = &output {
+	let mut out: Box<dyn io::Write> = if let Some(path) = output {
 		if path == Path::new("-") {
 			Box::new(io::stdout())
 		} else {
@@ -634,19 +1162,7 @@ pub fn run(mut args: Opt) -> Result<()> {
 	} else {
 		Box::new(io::stdout())
 	};
-	if args.bump.is_some() || args.bumped_version {
-		let next_version = if let Some(next_version) = changelog.bump_version()? {
-			next_version
-		} else if let Some(last_version) =
-			changelog.releases.first().cloned().and_then(|v| v.version)
-		{
-			warn!("There is nothing to bump.");
-			last_version
-		} else if changelog.releases.is_empty() {
-			config.bump.get_initial_tag()
-		} else {
-			return Ok(());
-		};
+	if let Some(next_version) = &next_version {
 		if args.bumped_version {
 			writeln!(out, "{next_version}")?;
 			return Ok(());
@@ -654,16 +1170,86 @@ pub fn run(mut args: Opt) -> Result<()> {
 	}
 	if args.context {
 		changelog.write_context(&mut out)?;
-		return Ok(());
+	} else {
+		if let Some(path) = &args.prepend {
+			let changelog_before = fs::read_to_string(path)?;
+			let mut out = io::BufWriter::new(File::create(path)?);
+			changelog.prepend(changelog_before, &mut out)?;
+			out.flush()?;
+		}
+		if output.is_some() || args.prepend.is_none() {
+			changelog.generate(&mut out)?;
+		}
 	}
-	if let Some(path) = &args.prepend {
-		let changelog_before = fs::read_to_string(path)?;
-		let mut out = io::BufWriter::new(File::create(path)?);
-		changelog.prepend(changelog_before, &mut out)?;
+	out.flush()?;
+
+	// Write the version files only after the changelog is written successfully.
+	if let Some(next_version) = next_version.filter(|_| args.write_version) {
+		let version = parse_version(&next_version)
+			.map(|(_, version)| version.to_string())
+			.unwrap_or_else(|_| next_version.clone());
+		let files = config.bump.version_files.clone().unwrap_or_default();
+		if files.is_empty() {
+			warn!("No version files are configured.");
+		}
+		for path in version::write_version_files(&files, &version)? {
+			info!("Updated the version in {path:?}");
+		}
 	}
-	if output.is_some() || args.prepend.is_none() {
-		changelog.generate(&mut out)?;
+
+	// Create the tag only after the changelog is written successfully.
+	if let Some((repository, name, message)) = tag {
+		repository.create_tag(&name, &message, args.sign_tag)?;
+		info!("Created the tag: {name}");
 	}
 
 	Ok(())
 }
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use clap::Parser;
+	use std::process::Command;
+	use temp_dir::TempDir;
+
+	fn git(dir: &Path, args: &[&str]) {
+		let output = Command::new("git")
+			.args(args)
+			.current_dir(dir)
+			.output()
+			.expect("failed to execute git");
+		assert!(output.status.success(), "git failed {:?}", output);
+	}
+
+	#[test]
+	fn context_lists_files() -> Result<()> {
+		let temp_dir = TempDir::with_prefix("git-cliff-")?;
+		git(temp_dir.path(), &["init"]);
+		git(temp_dir.path(), &["config", "user.email", "test@gmail.com"]);
+		git(temp_dir.path(), &["config", "user.name", "test"]);
+		fs::write(temp_dir.path().join("README.md"), "first\nsecond\n")?;
+		git(temp_dir.path(), &["add", "README.md"]);
+		git(temp_dir.path(), &["commit", "-m", "feat: add readme"]);
+
+		let repository: &'static Repository =
+			Box::leak(Box::new(Repository::init(temp_dir.path().to_path_buf())?));
+		let mut config = EmbeddedConfig::parse()?;
+		let args =
+			Opt::parse_from(["git-cliff", "--context", "--include-path", "**/*"]);
+		let releases = process_repository(repository, &mut config, &args)?
+			.into_iter()
+			.next()
+			.unwrap_or_default();
+		let changelog = Changelog::new(releases, &config)?;
+		let mut out = Vec::new();
+		changelog.write_context(&mut out)?;
+
+		let context = String::from_utf8(out).expect("invalid utf-8");
+		assert!(context.contains(r#""path":"README.md""#));
+		assert!(
+			context.contains(r#""stats":{"files":1,"insertions":2,"deletions":0}"#)
+		);
+		Ok(())
+	}
+}